@@ -12,4 +12,6 @@ pub enum DataKey {
     Payer,
     TransactionDataReceived(Bytes),
     TransactionList,
+    Commitment(Bytes),
+    CommitLedger(Bytes),
 }
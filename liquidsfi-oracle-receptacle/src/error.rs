@@ -1,3 +0,0 @@
-soroban_sdk::contractimport!(
-    file = "../../global_error/target/wasm32-unknown-unknown/release/liquidsfi_errors.wasm"
-);
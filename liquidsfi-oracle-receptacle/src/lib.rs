@@ -2,7 +2,7 @@
 mod access;
 mod data;
 mod decode;
-mod error;
-mod receptacle;
+mod oracle;
+pub mod receptacle;
 mod transfers;
 mod types;
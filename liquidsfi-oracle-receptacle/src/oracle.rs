@@ -0,0 +1,22 @@
+use liquidsfi_types::{error::ContractError, types::SourceSignature};
+use soroban_sdk::{contractclient, Address, Bytes, Env, String, Vec};
+
+// The part of the oracle interface a receptacle calls back into.
+#[allow(dead_code)]
+#[contractclient(name = "Client")]
+pub trait Oracle {
+    fn verify_source_payload(
+        e: Env,
+        tx_id: Bytes,
+        origin_chain: u32,
+        sender: String,
+        transfer_data: Bytes,
+        signature: Option<SourceSignature>,
+    ) -> Result<(), ContractError>;
+    fn get_reveal_delay(e: Env) -> Option<u32>;
+    fn push_attestation(
+        e: Env,
+        receptacle: Address,
+        attestation: Vec<Bytes>,
+    ) -> Result<bool, ContractError>;
+}
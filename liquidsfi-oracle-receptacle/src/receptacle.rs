@@ -1,4 +1,7 @@
-use soroban_sdk::{contract, contractimpl, xdr::ToXdr, Address, Bytes, Env, String, Val, Vec};
+use liquidsfi_types::error::ReceptacleError;
+use soroban_sdk::{
+    contract, contractimpl, xdr::ToXdr, Address, Bytes, BytesN, Env, String, Val, Vec,
+};
 
 use crate::{
     access::{
@@ -6,27 +9,49 @@ use crate::{
         write_payer,
     },
     decode::decode_transfer_data,
+    oracle,
     transfers::{
-        add_tx_to_list, delete_transaction, read_transaction, read_tx_list, remove_tx_from_list,
-        write_transaction,
+        add_tx_to_list, delete_commit_ledger, delete_commitment, delete_transaction,
+        read_commit_ledger, read_commitment, read_transaction, read_tx_list, remove_tx_from_list,
+        write_commit_ledger, write_commitment, write_transaction,
     },
-    types::{TransferDataReceived, TransferStatus},
+    types::{Commitment, SourceSignature, TransferDataReceived, TransferStatus},
 };
 
 pub trait ReceptacleTrait {
-    fn initialize(e: Env, oracle: Address, node_operator: Address);
-    fn set_operator(e: Env, node_operator: Address);
-    fn set_payer(e: Env, payer: Address);
+    fn initialize(e: Env, oracle: Address, node_operator: Address) -> Result<(), ReceptacleError>;
+    fn set_operator(e: Env, node_operator: Address) -> Result<(), ReceptacleError>;
+    fn set_payer(e: Env, payer: Address) -> Result<(), ReceptacleError>;
     fn commit_transfer(
         e: Env,
         tx_id: Bytes,
         origin_chain: u32,
         sender: String,
         transfer_data: Bytes,
-    );
-    fn remove_transfer(e: Env, tx_id: Bytes);
+    ) -> Result<(), ReceptacleError>;
+    fn commit_signed_transfer(
+        e: Env,
+        tx_id: Bytes,
+        origin_chain: u32,
+        sender: String,
+        transfer_data: Bytes,
+        signature: SourceSignature,
+    ) -> Result<(), ReceptacleError>;
+    fn commit_hash(e: Env, tx_id: Bytes, hash: BytesN<32>) -> Result<(), ReceptacleError>;
+    fn reveal_transfer(
+        e: Env,
+        tx_id: Bytes,
+        origin_chain: u32,
+        sender: String,
+        transfer_data: Bytes,
+        salt: BytesN<32>,
+        signature: Option<SourceSignature>,
+    ) -> Result<(), ReceptacleError>;
+    fn get_commitment(e: Env, tx_id: Bytes) -> Option<Commitment>;
+    fn remove_transfer(e: Env, tx_id: Bytes) -> Result<(), ReceptacleError>;
 
     fn get_transfer_id_list(e: Env) -> Val;
+    fn get_pending_transfers(e: Env, start: u32, limit: u32) -> Vec<(Bytes, u32)>;
 
     fn get_transfer(e: Env, tx_id: Bytes) -> Val;
     fn get_payer(e: Env) -> Address;
@@ -37,24 +62,27 @@ pub struct Receptacle;
 
 #[contractimpl]
 impl ReceptacleTrait for Receptacle {
-    fn initialize(e: Env, oracle: Address, node_operator: Address) {
+    fn initialize(e: Env, oracle: Address, node_operator: Address) -> Result<(), ReceptacleError> {
         if has_operator(&e) {
-            panic!("Tentacle has already been initialized")
+            return Err(ReceptacleError::AlreadyInitialized);
         }
         write_oracle(&e, &oracle);
         write_operator(&e, &node_operator);
-        write_payer(&e, &node_operator)
+        write_payer(&e, &node_operator);
+        Ok(())
     }
 
-    fn set_operator(e: Env, node_operator: Address) {
-        let cur_operator = read_operator(&e).unwrap();
+    fn set_operator(e: Env, node_operator: Address) -> Result<(), ReceptacleError> {
+        let cur_operator = read_operator(&e).ok_or(ReceptacleError::NotInitialized)?;
         cur_operator.require_auth();
         write_operator(&e, &node_operator);
+        Ok(())
     }
-    fn set_payer(e: Env, payer: Address) {
-        let operator = read_operator(&e).unwrap();
+    fn set_payer(e: Env, payer: Address) -> Result<(), ReceptacleError> {
+        let operator = read_operator(&e).ok_or(ReceptacleError::NotInitialized)?;
         operator.require_auth();
         write_payer(&e, &payer);
+        Ok(())
     }
 
     fn commit_transfer(
@@ -63,31 +91,97 @@ impl ReceptacleTrait for Receptacle {
         origin_chain: u32,
         sender: String,
         transfer_data: Bytes,
-    ) {
-        let node_operator = read_operator(&e).unwrap();
-        node_operator.require_auth();
-
-        let decoded_transfer_data = decode_transfer_data(e.clone(), transfer_data);
-
-        let received_data = TransferDataReceived {
-            tx_id: tx_id.clone(),
-            origin_chain: origin_chain,
-            sender: sender,
-            recipient: decoded_transfer_data.recipient,
-            token: decoded_transfer_data.token,
-            amount: decoded_transfer_data.amount,
-            status: TransferStatus::Received,
+    ) -> Result<(), ReceptacleError> {
+        authenticate_operator(&e)?;
+
+        store_transfer(&e, tx_id, origin_chain, sender, transfer_data, None)
+    }
+
+    fn commit_signed_transfer(
+        e: Env,
+        tx_id: Bytes,
+        origin_chain: u32,
+        sender: String,
+        transfer_data: Bytes,
+        signature: SourceSignature,
+    ) -> Result<(), ReceptacleError> {
+        authenticate_operator(&e)?;
+
+        store_transfer(
+            &e,
+            tx_id,
+            origin_chain,
+            sender,
+            transfer_data,
+            Some(signature),
+        )
+    }
+
+    fn commit_hash(e: Env, tx_id: Bytes, hash: BytesN<32>) -> Result<(), ReceptacleError> {
+        authenticate_operator(&e)?;
+
+        if read_commitment(&e, tx_id.clone()).is_some() {
+            return Err(ReceptacleError::AlreadyCommitted);
+        }
+        let commitment = Commitment {
+            hash,
+            ledger: e.ledger().sequence(),
+            revealed: false,
         };
-        write_transaction(&e, tx_id.clone(), received_data);
-        add_tx_to_list(&e, tx_id);
+        write_commitment(&e, tx_id, &commitment);
+        Ok(())
+    }
+
+    fn reveal_transfer(
+        e: Env,
+        tx_id: Bytes,
+        origin_chain: u32,
+        sender: String,
+        transfer_data: Bytes,
+        salt: BytesN<32>,
+        signature: Option<SourceSignature>,
+    ) -> Result<(), ReceptacleError> {
+        authenticate_operator(&e)?;
+
+        let oracle = read_oracle(&e).ok_or(ReceptacleError::NotInitialized)?;
+        let reveal_delay = oracle::Client::new(&e, &oracle)
+            .get_reveal_delay()
+            .ok_or(ReceptacleError::CommitRevealDisabled)?;
+
+        let mut commitment =
+            read_commitment(&e, tx_id.clone()).ok_or(ReceptacleError::NotCommitted)?;
+        if e.ledger().sequence() < commitment.ledger.saturating_add(reveal_delay) {
+            return Err(ReceptacleError::RevealWindowNotOpen);
+        }
+
+        // Commitments cover sha256(tx_id || origin_chain (big endian) || sender xdr || transfer_data || salt).
+        let mut preimage = tx_id.clone();
+        preimage.extend_from_array(&origin_chain.to_be_bytes());
+        preimage.append(&sender.clone().to_xdr(&e));
+        preimage.append(&transfer_data);
+        preimage.extend_from_array(&salt.to_array());
+        if BytesN::from(e.crypto().sha256(&preimage)) != commitment.hash {
+            return Err(ReceptacleError::RevealMismatch);
+        }
+        commitment.revealed = true;
+        write_commitment(&e, tx_id.clone(), &commitment);
+
+        store_transfer(&e, tx_id, origin_chain, sender, transfer_data, signature)
     }
 
-    fn remove_transfer(e: Env, tx_id: Bytes) {
-        let oracle = read_oracle(&e).unwrap();
+    fn get_commitment(e: Env, tx_id: Bytes) -> Option<Commitment> {
+        read_commitment(&e, tx_id)
+    }
+
+    fn remove_transfer(e: Env, tx_id: Bytes) -> Result<(), ReceptacleError> {
+        let oracle = read_oracle(&e).ok_or(ReceptacleError::NotInitialized)?;
         oracle.require_auth();
 
+        delete_commitment(&e, tx_id.clone());
+        delete_commit_ledger(&e, tx_id.clone());
         delete_transaction(&e, tx_id.clone());
         remove_tx_from_list(&e, tx_id);
+        Ok(())
     }
 
     fn get_transfer_id_list(e: Env) -> Val {
@@ -95,6 +189,20 @@ impl ReceptacleTrait for Receptacle {
         list.to_val()
     }
 
+    // Pages follow the order the transfers were stored in, oldest first. Transfers stored before
+    // commit ledgers were recorded report ledger 0.
+    fn get_pending_transfers(e: Env, start: u32, limit: u32) -> Vec<(Bytes, u32)> {
+        let tx_list = read_tx_list(&e);
+        let end = u32::min(start.saturating_add(limit), tx_list.len());
+        let mut pending = Vec::new(&e);
+        for index in start..end {
+            let tx_id = tx_list.get_unchecked(index);
+            let ledger = read_commit_ledger(&e, tx_id.clone()).unwrap_or(0);
+            pending.push_back((tx_id, ledger));
+        }
+        pending
+    }
+
     // fn get_transfer(e: Env, tx_id: Bytes) -> Result<TransferDataReceived, ContractError> {
     //     if let Some(tx_data) = read_transaction(&e, tx_id) {
 
@@ -109,21 +217,76 @@ impl ReceptacleTrait for Receptacle {
     // }
 
     fn get_transfer(e: Env, tx_id: Bytes) -> Val {
-        let mut data_arr: Vec<Bytes> = Vec::new(&e);
-
-        if let Some(tx_data) = read_transaction(&e, tx_id) {
-            data_arr.push_back(tx_data.clone().tx_id);
-            data_arr.push_back(tx_data.clone().origin_chain.to_xdr(&e));
-            data_arr.push_back(tx_data.clone().sender.to_xdr(&e));
-            data_arr.push_back(tx_data.clone().recipient.to_xdr(&e));
-            data_arr.push_back(tx_data.clone().token.to_xdr(&e));
-            data_arr.push_back(tx_data.clone().amount.to_xdr(&e));
+        match read_transaction(&e, tx_id) {
+            Some(tx_data) => attestation(&e, tx_data).to_val(),
+            None => Vec::<Bytes>::new(&e).to_val(),
         }
-
-        data_arr.to_val()
     }
 
     fn get_payer(e: Env) -> Address {
         read_payer(&e).unwrap()
     }
 }
+
+fn authenticate_operator(e: &Env) -> Result<(), ReceptacleError> {
+    let operator = read_operator(e).ok_or(ReceptacleError::NotInitialized)?;
+    operator.require_auth();
+    Ok(())
+}
+
+// Callers authenticate the operator. The oracle rejects payloads that lack a valid source signature on chains that require one.
+fn store_transfer(
+    e: &Env,
+    tx_id: Bytes,
+    origin_chain: u32,
+    sender: String,
+    transfer_data: Bytes,
+    signature: Option<SourceSignature>,
+) -> Result<(), ReceptacleError> {
+    let decoded_transfer_data = decode_transfer_data(&transfer_data)?;
+
+    let oracle = read_oracle(e).ok_or(ReceptacleError::NotInitialized)?;
+    let oracle_client = oracle::Client::new(e, &oracle);
+    oracle_client.verify_source_payload(&tx_id, &origin_chain, &sender, &transfer_data, &signature);
+
+    let received_data = TransferDataReceived {
+        tx_id: tx_id.clone(),
+        origin_chain,
+        sender,
+        recipient: decoded_transfer_data.recipient,
+        token: decoded_transfer_data.token,
+        amount: decoded_transfer_data.amount,
+        status: TransferStatus::Received,
+    };
+
+    // Pushing the vote lets the oracle execute as soon as the threshold is met. A failed push
+    // leaves the transfer queued here for upkeep.
+    let pushed = oracle_client.try_push_attestation(
+        &e.current_contract_address(),
+        &attestation(e, received_data.clone()),
+    );
+    if let Ok(Ok(true)) = pushed {
+        delete_commitment(e, tx_id);
+        return Ok(());
+    }
+
+    write_transaction(e, tx_id.clone(), received_data);
+    write_commit_ledger(e, tx_id.clone());
+    add_tx_to_list(e, tx_id);
+    Ok(())
+}
+
+// Transfer fields as the oracle tallies them, each XDR-encoded except the id.
+fn attestation(e: &Env, tx_data: TransferDataReceived) -> Vec<Bytes> {
+    Vec::from_array(
+        e,
+        [
+            tx_data.tx_id,
+            tx_data.origin_chain.to_xdr(e),
+            tx_data.sender.to_xdr(e),
+            tx_data.recipient.to_xdr(e),
+            tx_data.token.to_xdr(e),
+            tx_data.amount.to_xdr(e),
+        ],
+    )
+}
@@ -1,8 +1,8 @@
-use soroban_sdk::{Bytes, Env, String, Vec};
+use soroban_sdk::{Bytes, Env, Vec};
 
 use crate::{
     data::{DataKey, BUMP_AMOUNT, LIFETIME_THRESHOLD},
-    types::{TransferDataReceived, TransferStatus},
+    types::{Commitment, TransferDataReceived},
 };
 
 pub fn read_transaction(e: &Env, tx_id: Bytes) -> Option<TransferDataReceived> {
@@ -30,7 +30,7 @@ pub fn delete_transaction(e: &Env, tx_id: Bytes) {
 
 pub fn read_tx_list(e: &Env) -> Vec<Bytes> {
     let key = DataKey::TransactionList;
-    let default_list: Vec<Bytes> = Vec::new(&e);
+    let default_list: Vec<Bytes> = Vec::new(e);
 
     e.storage()
         .instance()
@@ -56,3 +56,42 @@ pub fn remove_tx_from_list(e: &Env, tx_id: Bytes) {
         write_tx_list(e, tx_list);
     }
 }
+
+pub fn read_commitment(e: &Env, tx_id: Bytes) -> Option<Commitment> {
+    let key = DataKey::Commitment(tx_id);
+    e.storage().persistent().get(&key)
+}
+
+pub fn write_commitment(e: &Env, tx_id: Bytes, commitment: &Commitment) {
+    let key = DataKey::Commitment(tx_id);
+
+    e.storage().persistent().set(&key, commitment);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+pub fn delete_commitment(e: &Env, tx_id: Bytes) {
+    let key = DataKey::Commitment(tx_id);
+    e.storage().persistent().remove(&key);
+}
+
+// Ledger at which a transfer was stored, so the oracle can process the oldest transfers first.
+pub fn read_commit_ledger(e: &Env, tx_id: Bytes) -> Option<u32> {
+    let key = DataKey::CommitLedger(tx_id);
+    e.storage().persistent().get(&key)
+}
+
+pub fn write_commit_ledger(e: &Env, tx_id: Bytes) {
+    let key = DataKey::CommitLedger(tx_id);
+
+    e.storage().persistent().set(&key, &e.ledger().sequence());
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+pub fn delete_commit_ledger(e: &Env, tx_id: Bytes) {
+    let key = DataKey::CommitLedger(tx_id);
+    e.storage().persistent().remove(&key);
+}
@@ -1,3 +1 @@
-soroban_sdk::contractimport!(
-    file = "../../shared_files/target/wasm32-unknown-unknown/release/liquidsfi_types.wasm"
-);
+pub use liquidsfi_types::types::*;
@@ -0,0 +1,96 @@
+use crate::types::DecodedTransferData;
+use soroban_sdk::{Address, Bytes};
+
+const STRKEY_LENGTH: u32 = 56;
+const ACCOUNT_VERSION_BYTE: u8 = 6 << 3;
+const CONTRACT_VERSION_BYTE: u8 = 2 << 3;
+
+// Payloads are checked up front because the host traps on an invalid strkey instead of returning
+// an error. The oracle and the receptacles both decode through here so they reject the same
+// payloads, each with its own error.
+pub fn decode_transfer_data(transfer_bytes: &Bytes) -> Option<DecodedTransferData> {
+    if transfer_bytes.len() < 312 {
+        return None;
+    }
+
+    let recipient = decode_address(transfer_bytes, 159)?;
+    let token = decode_address(transfer_bytes, 255)?;
+
+    // The amount is a 32-byte word that has to fit a non-negative i128.
+    let mut amount_word = [0u8; 32];
+    transfer_bytes
+        .slice(96..128)
+        .copy_into_slice(&mut amount_word);
+    if amount_word[..16] != [0u8; 16] || amount_word[16] >= 0x80 {
+        return None;
+    }
+    let mut amount_bytes = [0u8; 16];
+    amount_bytes.copy_from_slice(&amount_word[16..]);
+
+    Some(DecodedTransferData {
+        recipient,
+        token,
+        amount: i128::from_be_bytes(amount_bytes),
+    })
+}
+
+// Strkeys are stored as a length byte followed by the 56 characters of the key.
+fn decode_address(transfer_bytes: &Bytes, length_index: u32) -> Option<Address> {
+    if transfer_bytes.get_unchecked(length_index) as u32 != STRKEY_LENGTH {
+        return None;
+    }
+    let strkey = transfer_bytes.slice(length_index + 1..length_index + 1 + STRKEY_LENGTH);
+
+    let mut characters = [0u8; STRKEY_LENGTH as usize];
+    strkey.copy_into_slice(&mut characters);
+    if !is_valid_strkey(&characters) {
+        return None;
+    }
+
+    Some(Address::from_string_bytes(&strkey))
+}
+
+// An account or contract strkey is the base32 encoding of a version byte, a 32-byte key and a
+// crc16 (xmodem) checksum of both in little endian.
+fn is_valid_strkey(characters: &[u8; STRKEY_LENGTH as usize]) -> bool {
+    let mut decoded = [0u8; 35];
+    let mut buffer: u32 = 0;
+    let mut bits: u32 = 0;
+    let mut index = 0;
+    for character in characters.iter() {
+        let value = match character {
+            b'A'..=b'Z' => character - b'A',
+            b'2'..=b'7' => character - b'2' + 26,
+            _ => return false,
+        };
+        buffer = (buffer << 5) | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            decoded[index] = (buffer >> bits) as u8;
+            buffer &= (1 << bits) - 1;
+            index += 1;
+        }
+    }
+
+    if decoded[0] != ACCOUNT_VERSION_BYTE && decoded[0] != CONTRACT_VERSION_BYTE {
+        return false;
+    }
+    let checksum = u16::from_le_bytes([decoded[33], decoded[34]]);
+    checksum == crc16_xmodem(&decoded[..33])
+}
+
+fn crc16_xmodem(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for byte in data.iter() {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
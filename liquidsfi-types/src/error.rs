@@ -0,0 +1,85 @@
+use soroban_sdk::contracterror;
+
+// Codes are part of the contract interface and are frozen: a code is never renumbered or given a
+// new meaning. Retired codes stay unused: 10 (TokenNotSupported, now TokenChainMapNotFound),
+// 13 (TransferNotFound), 51 (InvalidConsensusQuorum, now InvalidConsensusPolicy), 63
+// (InsufficientBond, now InvalidAmount), 64 (NothingToWithdraw, now UnbondingNotReady) and 81
+// (InsufficientSignatures, now QuorumNotReached). contracterror caps an enum at 50 cases.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    Unauthorized = 3,
+    AlreadyNodeOperator = 4,
+    NodeNotFound = 5,
+    ReceptacleNotFound = 6,
+    NoReceptacles = 7,
+    ChainAlreadyAdded = 8,
+    ChainNotFound = 9,
+    TokenChainMapNotFound = 11,
+    BridgeNotFound = 12,
+    MalformedTransferData = 14,
+
+    AlreadyManager = 20,
+    ManagerNotFound = 21,
+    NoPendingAdmin = 22,
+    GuardianNotFound = 23,
+    Paused = 24,
+    ChainPaused = 25,
+    TokenChainPaused = 26,
+
+    CouncilAlreadySet = 30,
+    NotCouncilMember = 31,
+    DuplicateCouncilMember = 32,
+    InvalidQuorum = 33,
+    GovernedByCouncil = 34,
+    ProposalNotFound = 35,
+    ProposalExpired = 36,
+    ProposalAlreadyExecuted = 37,
+    AlreadyApproved = 38,
+    QuorumNotReached = 39,
+
+    UpgradeAlreadyPending = 40,
+    NoPendingUpgrade = 41,
+    UpgradeNotReady = 42,
+    InvalidUpgradeDelay = 43,
+
+    InvalidConsensusPolicy = 50,
+
+    StakingNotEnabled = 60,
+    InvalidBondConfig = 61,
+    InvalidAmount = 62,
+    UnbondingNotReady = 65,
+
+    InvalidSlashingConfig = 70,
+    OperatorJailed = 71,
+    OperatorNotJailed = 72,
+
+    SignerKeyInUse = 80,
+    TransferAlreadyProcessed = 82,
+
+    SourceSignatureRequired = 90,
+    InvalidSourceSignature = 91,
+
+    InvalidUpkeepConfig = 100,
+    InvalidKeeperRewardConfig = 101,
+    KeeperRewardsNotEnabled = 102,
+}
+
+// Errors raised by a node's receptacle. The range starts at 200 so a receptacle failure that
+// surfaces through an oracle call cannot be mistaken for an oracle error.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ReceptacleError {
+    AlreadyInitialized = 200,
+    NotInitialized = 201,
+    AlreadyCommitted = 202,
+    CommitRevealDisabled = 203,
+    NotCommitted = 204,
+    RevealWindowNotOpen = 205,
+    RevealMismatch = 206,
+    MalformedTransferData = 207,
+}
@@ -0,0 +1,86 @@
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, String};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum TransferStatus {
+    Received,
+    Executed,
+}
+
+// Outgoing transfer as emitted to the destination chain.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TransferData {
+    pub recipient: String,
+    pub token: String,
+    pub amount: i128,
+}
+
+// Inbound transfer as committed by a node operator to its receptacle.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TransferDataReceived {
+    pub tx_id: Bytes,
+    pub origin_chain: u32,
+    pub sender: String,
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub status: TransferStatus,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DecodedTransferData {
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ChainOracleDetails {
+    pub chain_name: String,
+    pub chain_id: u32,
+    pub oracle_address: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DestinationChainDetails {
+    pub chain_name: String,
+    pub chain_id: u32,
+    pub destination_token: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ReceptacleDetails {
+    pub operator: Address,
+    pub receptacle_address: Address,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AllFees {
+    pub bridge_fee: i128,
+    pub oracle_fee: i128,
+}
+
+// Signature of the source-chain oracle over the keccak256 hash of an inbound transfer's signing payload.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SourceSignature {
+    pub signature: BytesN<64>,
+    pub recovery_id: u32,
+}
+
+// Hash an operator commits to before revealing the attestation it covers. Revealed is set only
+// once the stored attestation matched the hash.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Commitment {
+    pub hash: BytesN<32>,
+    pub ledger: u32,
+    pub revealed: bool,
+}
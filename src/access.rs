@@ -1,15 +1,18 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Env, Vec};
 
-use crate::data::DataKey;
+use crate::{data::DataKey, error::ContractError};
 
 pub fn has_admin(e: &Env) -> bool {
     let key = DataKey::Admin;
     e.storage().instance().has(&key)
 }
 
-pub fn read_admin(e: &Env) -> Option<Address> {
+pub fn read_admin(e: &Env) -> Result<Address, ContractError> {
     let key = DataKey::Admin;
-    e.storage().instance().get(&key).expect("Admin not found!")
+    e.storage()
+        .instance()
+        .get(&key)
+        .ok_or(ContractError::NotInitialized)
 }
 
 pub fn write_admin(e: &Env, admin: &Address) {
@@ -17,37 +20,88 @@ pub fn write_admin(e: &Env, admin: &Address) {
     e.storage().instance().set(&key, admin);
 }
 
-pub fn authenticate_admin(e: &Env) {
-    let admin = read_admin(e).unwrap();
+pub fn read_pending_admin(e: &Env) -> Option<Address> {
+    let key = DataKey::PendingAdmin;
+    e.storage().instance().get(&key)
+}
+
+pub fn write_pending_admin(e: &Env, pending_admin: &Address) {
+    let key = DataKey::PendingAdmin;
+    e.storage().instance().set(&key, pending_admin);
+}
+
+pub fn clear_pending_admin(e: &Env) {
+    let key = DataKey::PendingAdmin;
+    e.storage().instance().remove(&key);
+}
+
+pub fn authenticate_admin(e: &Env) -> Result<(), ContractError> {
+    let admin = read_admin(e)?;
     admin.require_auth();
+    Ok(())
 }
 
-pub fn read_manager(e: &Env) -> Option<Address> {
+pub fn read_managers(e: &Env) -> Vec<Address> {
     let key = DataKey::Managers;
-    e.storage().instance().get(&key).expect("Admin not found!")
+    let default_list: Vec<Address> = Vec::new(e);
+
+    e.storage()
+        .instance()
+        .get::<DataKey, Vec<Address>>(&key)
+        .unwrap_or(default_list)
 }
 
-pub fn write_manager(e: &Env, manager: &Address) {
+pub fn write_managers(e: &Env, managers: &Vec<Address>) {
     let key = DataKey::Managers;
-    e.storage().instance().set(&key, manager);
+    e.storage().instance().set(&key, managers);
+}
+
+pub fn is_manager(e: &Env, manager: &Address) -> bool {
+    read_managers(e).contains(manager)
 }
 
-pub fn authenticate_manager(e: &Env) {
-    let manager = read_manager(e).unwrap();
+pub fn write_manager(e: &Env, manager: &Address) {
+    let mut list = read_managers(e);
+    list.push_back(manager.clone());
+    write_managers(e, &list);
+}
+
+pub fn delete_manager(e: &Env, manager: &Address) {
+    let mut list = read_managers(e);
+    if let Some(index) = list.first_index_of(manager) {
+        list.remove(index);
+        write_managers(e, &list);
+    }
+}
+
+// The admin can always act as a manager without adding itself to the set.
+pub fn authenticate_manager(e: &Env, manager: &Address) -> Result<(), ContractError> {
+    if !is_manager(e, manager) && *manager != read_admin(e)? {
+        return Err(ContractError::Unauthorized);
+    }
     manager.require_auth();
+    Ok(())
 }
 
-pub fn read_bridge_contract(e: &Env) -> Option<Address> {
-    let key = DataKey::BridgeContract;
-    e.storage().instance().get(&key).expect("Admin not found!")
+pub fn read_guardian(e: &Env) -> Option<Address> {
+    let key = DataKey::Guardian;
+    e.storage().instance().get(&key)
 }
 
-pub fn write_bridge_contract(e: &Env, bridge_contract: &Address) {
+pub fn write_guardian(e: &Env, guardian: &Address) {
+    let key = DataKey::Guardian;
+    e.storage().instance().set(&key, guardian);
+}
+
+pub fn read_bridge_contract(e: &Env) -> Result<Address, ContractError> {
     let key = DataKey::BridgeContract;
-    e.storage().instance().set(&key, bridge_contract);
+    e.storage()
+        .instance()
+        .get(&key)
+        .ok_or(ContractError::BridgeNotFound)
 }
 
-pub fn has_bridge_contract(e: &Env) -> bool {
+pub fn write_bridge_contract(e: &Env, bridge_contract: &Address) {
     let key = DataKey::BridgeContract;
-    e.storage().instance().has(&key)
+    e.storage().instance().set(&key, bridge_contract);
 }
@@ -1,7 +1,10 @@
-#![allow(unused)]
-use crate::{error::ContractError, types::AllFees, types::TransferDataReceived};
-use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, String};
+use soroban_sdk::{contractclient, Address, Env};
 
-soroban_sdk::contractimport!(
-    file = "../liquidsfi-bridge-liquidity/target/wasm32-unknown-unknown/release/liquidsfi_bridge_liquidity.wasm"
-);
+// Interface of the LiquidsFi bridge liquidity contract the oracle settles transfers against.
+#[allow(dead_code)]
+#[contractclient(name = "Client")]
+pub trait BridgeLiquidity {
+    fn transfer_soroban_to_evm(e: Env, user: Address, token: Address, amount: i128);
+    fn execute_rebalancing(e: Env, user: Address, token: Address, amount: i128);
+    fn execute_evm_to_soroban_transfer(e: Env, recipient: Address, token: Address, amount: i128);
+}
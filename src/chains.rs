@@ -1,25 +1,30 @@
-use soroban_sdk::{Env, String, Vec};
+use soroban_sdk::{xdr::ToXdr, Bytes, BytesN, Env, String, Vec};
 
-use crate::{data::DataKey, types::ChainOracleDetails};
+use crate::{
+    data::DataKey,
+    error::ContractError,
+    pause::write_chain_paused,
+    types::{ChainOracleDetails, SourceSignature},
+};
 
 pub fn chain_is_supported(e: &Env, chain_id: u32) -> bool {
     let key = DataKey::ChainOracle(chain_id);
     e.storage().instance().has(&key)
 }
 
-pub fn read_chain(e: &Env, chain_id: u32) -> Option<ChainOracleDetails> {
+pub fn read_chain(e: &Env, chain_id: u32) -> Result<ChainOracleDetails, ContractError> {
     let key = DataKey::ChainOracle(chain_id);
     e.storage()
         .instance()
         .get(&key)
-        .expect("Oracle address not found!")
+        .ok_or(ContractError::ChainNotFound)
 }
 
 pub fn write_chain(e: &Env, chain_name: String, chain_id: u32, oracle_address: &String) {
     let key = DataKey::ChainOracle(chain_id);
     let chain_details = ChainOracleDetails {
-        chain_name: chain_name,
-        chain_id: chain_id,
+        chain_name,
+        chain_id,
         oracle_address: oracle_address.clone(),
     };
     e.storage().instance().set(&key, &chain_details);
@@ -34,10 +39,12 @@ pub fn write_chain(e: &Env, chain_name: String, chain_id: u32, oracle_address: &
 pub fn delete_chain(e: &Env, chain_id: u32) {
     let key = DataKey::ChainOracle(chain_id);
     e.storage().instance().remove(&key);
+    write_chain_paused(e, chain_id, false);
+    write_chain_signers(e, chain_id, &Vec::new(e));
 
     let list = read_chain_id_list(e);
 
-    let mut updated_list: Vec<u32> = Vec::new(&e);
+    let mut updated_list: Vec<u32> = Vec::new(e);
 
     for i in 0..list.len() {
         if list.get_unchecked(i) != chain_id {
@@ -51,7 +58,7 @@ pub fn delete_chain(e: &Env, chain_id: u32) {
 
 pub fn read_chain_id_list(e: &Env) -> Vec<u32> {
     let key = DataKey::ChainIdList;
-    let default_list: Vec<u32> = Vec::new(&e);
+    let default_list: Vec<u32> = Vec::new(e);
 
     e.storage()
         .instance()
@@ -62,7 +69,7 @@ pub fn read_chain_id_list(e: &Env) -> Vec<u32> {
 pub fn read_supported_chain_list(e: &Env) -> Vec<ChainOracleDetails> {
     let chain_id_list = read_chain_id_list(e);
 
-    let mut chain_list: Vec<ChainOracleDetails> = Vec::new(&e);
+    let mut chain_list: Vec<ChainOracleDetails> = Vec::new(e);
 
     let length = chain_id_list.len();
 
@@ -71,11 +78,106 @@ pub fn read_supported_chain_list(e: &Env) -> Vec<ChainOracleDetails> {
     }
 
     for i in 0..length {
-        let chain_details: ChainOracleDetails =
-            read_chain(e, chain_id_list.get_unchecked(i)).unwrap();
-
-        chain_list.push_back(chain_details)
+        if let Ok(chain_details) = read_chain(e, chain_id_list.get_unchecked(i)) {
+            chain_list.push_back(chain_details)
+        }
     }
 
     chain_list
 }
+
+pub fn read_chain_signers(e: &Env, chain_id: u32) -> Vec<BytesN<20>> {
+    let key = DataKey::ChainSigners(chain_id);
+    e.storage().instance().get(&key).unwrap_or(Vec::new(e))
+}
+
+pub fn write_chain_signers(e: &Env, chain_id: u32, signers: &Vec<BytesN<20>>) {
+    let key = DataKey::ChainSigners(chain_id);
+    if signers.is_empty() {
+        e.storage().instance().remove(&key);
+    } else {
+        e.storage().instance().set(&key, signers);
+    }
+}
+
+// secp256k1 group order and half of it, big endian.
+const SECP256K1_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+const SECP256K1_HALF_ORDER: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
+// Source oracles sign keccak256(oracle address xdr || origin_chain (big endian) || tx_id xdr ||
+// sender xdr || transfer_data), so a signature only covers one transfer on one deployment.
+pub fn source_signing_payload(
+    e: &Env,
+    tx_id: &Bytes,
+    origin_chain: u32,
+    sender: &String,
+    transfer_data: &Bytes,
+) -> Bytes {
+    let mut payload = e.current_contract_address().to_xdr(e);
+    payload.extend_from_array(&origin_chain.to_be_bytes());
+    payload.append(&tx_id.clone().to_xdr(e));
+    payload.append(&sender.clone().to_xdr(e));
+    payload.append(transfer_data);
+    payload
+}
+
+// Chains without registered signers rely on consensus alone.
+pub fn verify_source_payload(
+    e: &Env,
+    tx_id: &Bytes,
+    origin_chain: u32,
+    sender: &String,
+    transfer_data: &Bytes,
+    signature: Option<SourceSignature>,
+) -> Result<(), ContractError> {
+    let signers = read_chain_signers(e, origin_chain);
+    if signers.is_empty() {
+        return Ok(());
+    }
+    let signature = signature.ok_or(ContractError::SourceSignatureRequired)?;
+
+    // EVM signatures carry v as 27 or 28.
+    let recovery_id = if signature.recovery_id >= 27 {
+        signature.recovery_id - 27
+    } else {
+        signature.recovery_id
+    };
+    if recovery_id > 1 {
+        return Err(ContractError::InvalidSourceSignature);
+    }
+
+    // The host traps on r or s outside [1, n) and on a high s, so those are rejected up front.
+    let signature_bytes = signature.signature.to_array();
+    let r: [u8; 32] = signature_bytes[..32].try_into().unwrap();
+    let s: [u8; 32] = signature_bytes[32..].try_into().unwrap();
+    if r == [0; 32] || r >= SECP256K1_ORDER || s == [0; 32] || s > SECP256K1_HALF_ORDER {
+        return Err(ContractError::InvalidSourceSignature);
+    }
+
+    let payload = source_signing_payload(e, tx_id, origin_chain, sender, transfer_data);
+    let digest = e.crypto().keccak256(&payload);
+    let public_key = e
+        .crypto()
+        .secp256k1_recover(&digest, &signature.signature, recovery_id);
+
+    if !signers.contains(evm_address(e, &public_key)) {
+        return Err(ContractError::InvalidSourceSignature);
+    }
+    Ok(())
+}
+
+// The EVM address is the last 20 bytes of the keccak256 hash of the uncompressed key without its prefix.
+fn evm_address(e: &Env, public_key: &BytesN<65>) -> BytesN<20> {
+    let key_bytes = Bytes::from_array(e, &public_key.to_array()).slice(1..);
+    let hash = e.crypto().keccak256(&key_bytes).to_array();
+
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    BytesN::from_array(e, &address)
+}
@@ -1,59 +1,174 @@
-use soroban_sdk::{Bytes, Env, Vec};
+use soroban_sdk::{contracttype, Bytes, BytesN, Env, Map, Vec};
 
-use crate::data::DataKey;
+use crate::{
+    data::{DataKey, MAX_REVEAL_DELAY},
+    error::ContractError,
+    faults::read_jailed_count,
+    receptacle_ids::read_operator_list,
+    staking::{is_stake_weighted, read_total_stake},
+};
+
+pub(crate) const MAX_BPS: u32 = 10_000;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ConsensusPolicy {
+    Absolute(u32), //number of agreeing operators
+    Fraction(u32), //basis points of active operators, rounded up
+}
+
+pub fn read_consensus_policy(e: &Env) -> ConsensusPolicy {
+    let key = DataKey::ConsensusPolicy;
+    e.storage()
+        .instance()
+        .get(&key)
+        .unwrap_or(ConsensusPolicy::Fraction(MAX_BPS))
+}
+
+pub fn write_consensus_policy(e: &Env, policy: &ConsensusPolicy) {
+    let key = DataKey::ConsensusPolicy;
+    e.storage().instance().set(&key, policy);
+}
+
+// Both forms must require a strict majority so two conflicting attestations can never both pass.
+pub fn validate_consensus_policy(
+    policy: &ConsensusPolicy,
+    operator_count: u32,
+) -> Result<(), ContractError> {
+    let is_valid = match policy {
+        ConsensusPolicy::Absolute(count) => *count * 2 > operator_count && *count <= operator_count,
+        ConsensusPolicy::Fraction(bps) => *bps * 2 > MAX_BPS && *bps <= MAX_BPS,
+    };
+
+    if !is_valid {
+        return Err(ContractError::InvalidConsensusPolicy);
+    }
+    Ok(())
+}
+
+pub fn consensus_threshold(policy: &ConsensusPolicy, operator_count: u32) -> u32 {
+    match policy {
+        ConsensusPolicy::Absolute(count) => *count,
+        ConsensusPolicy::Fraction(bps) => {
+            (operator_count as u64 * *bps as u64).div_ceil(MAX_BPS as u64) as u32
+        }
+    }
+}
 
 pub fn read_consensus_threshold(e: &Env) -> u32 {
-    let key = DataKey::ConsensusThreshold;
-    e.storage().instance().get(&key).unwrap_or(0)
+    let active_count = read_operator_list(e).len() - read_jailed_count(e);
+    consensus_threshold(&read_consensus_policy(e), active_count)
 }
 
-pub fn write_consensus_threshold(e: &Env, consensus_val: u32) {
-    let key = DataKey::ConsensusThreshold;
-    e.storage().instance().set(&key, &consensus_val);
+pub fn read_consensus_quorum(e: &Env) -> u32 {
+    let key = DataKey::ConsensusQuorum;
+    e.storage().instance().get(&key).unwrap_or(1)
 }
 
-pub fn read_consensus_count(e: &Env, data: Bytes) -> u32 {
-    let key = DataKey::ConsensusCount(data);
-    e.storage().instance().get(&key).unwrap_or(0)
+pub fn write_consensus_quorum(e: &Env, quorum: u32) {
+    let key = DataKey::ConsensusQuorum;
+    e.storage().instance().set(&key, &quorum);
 }
 
-pub fn write_consensus_count(e: &Env, data: Bytes, count: u32) {
-    let key = DataKey::ConsensusCount(data);
-    e.storage().instance().set(&key, &count);
+pub fn validate_consensus_quorum(quorum: u32, operator_count: u32) -> Result<(), ContractError> {
+    if quorum == 0 || quorum > operator_count {
+        return Err(ContractError::InvalidConsensusPolicy);
+    }
+    Ok(())
 }
 
-pub fn clear_consensus_count(e: &Env, data: Bytes) {
-    let key = DataKey::ConsensusCount(data);
-    e.storage().instance().remove(&key);
+pub fn read_reveal_delay(e: &Env) -> Option<u32> {
+    let key = DataKey::RevealDelay;
+    e.storage().instance().get(&key)
 }
 
-pub fn threshold_validation(e: &Env, data_list: Vec<Bytes>) -> Option<Bytes> {
-    if data_list.len() == 0 {
-        return None;
+// The delay is capped so commitments stay revealable well within their storage lifetime.
+pub fn validate_reveal_delay(reveal_delay: Option<u32>) -> Result<(), ContractError> {
+    if reveal_delay.is_some_and(|delay| delay == 0 || delay > MAX_REVEAL_DELAY) {
+        return Err(ContractError::InvalidConsensusPolicy);
     }
-    let consensus_threshold = read_consensus_threshold(e);
-    let mut distinct_data_list: Vec<Bytes> = Vec::new(e);
+    Ok(())
+}
 
-    // Count occurrences of each transaction hash
-    for i in 0..data_list.len() {
-        let tx_data = data_list.get_unchecked(i);
-        let count = read_consensus_count(e, tx_data.clone()) + 1;
+pub fn write_reveal_delay(e: &Env, reveal_delay: Option<u32>) {
+    let key = DataKey::RevealDelay;
+    match reveal_delay {
+        Some(reveal_delay) => e.storage().instance().set(&key, &reveal_delay),
+        None => e.storage().instance().remove(&key),
+    }
+}
 
-        if !distinct_data_list.contains(tx_data.clone()) {
-            distinct_data_list.push_back(tx_data.clone());
+// Pushed votes execute transfers as soon as they reach the threshold unless pushes are switched off.
+pub fn read_push_enabled(e: &Env) -> bool {
+    let key = DataKey::PushDisabled;
+    !e.storage().instance().has(&key)
+}
+
+pub fn write_push_enabled(e: &Env, enabled: bool) {
+    let key = DataKey::PushDisabled;
+    if enabled {
+        e.storage().instance().remove(&key);
+    } else {
+        e.storage().instance().set(&key, &true);
+    }
+}
+
+// Only a full transfer record for the requested id counts as a vote; nodes that never saw it return an empty list.
+pub fn is_valid_attestation(tx_id: &Bytes, attestation: &Vec<Bytes>) -> bool {
+    attestation.len() == 6 && attestation.get_unchecked(0) == *tx_id
+}
+
+// Stake thresholds only support fractions since an absolute vote count means nothing once votes are weighted.
+pub fn stake_threshold(policy: &ConsensusPolicy, total_stake: i128) -> Option<i128> {
+    match policy {
+        ConsensusPolicy::Absolute(_) => None,
+        ConsensusPolicy::Fraction(bps) => {
+            Some((total_stake * *bps as i128 + MAX_BPS as i128 - 1) / MAX_BPS as i128)
         }
+    }
+}
 
-        write_consensus_count(e, tx_data, count);
+pub fn read_vote_threshold(e: &Env) -> i128 {
+    if !is_stake_weighted(e) {
+        return read_consensus_threshold(e) as i128;
     }
+    stake_threshold(&read_consensus_policy(e), read_total_stake(e)).unwrap_or(i128::MAX)
+}
 
-    // Find the most common hash that meets the consensus threshold
-    for i in 0..distinct_data_list.len() {
-        let validated_data = distinct_data_list.get_unchecked(i);
-        let validation_count = read_consensus_count(e, validated_data.clone());
-        if validation_count >= consensus_threshold {
-            return Some(validated_data);
+// Votes pair an attestation with the weight of the node that made it and are tallied in memory
+// by attestation hash, so nothing is persisted between invocations.
+pub fn threshold_validation(e: &Env, votes: Vec<(Bytes, i128)>) -> Option<Bytes> {
+    if votes.is_empty() || votes.len() < read_consensus_quorum(e) {
+        return None;
+    }
+    let vote_threshold = read_vote_threshold(e);
+    let mut tally: Map<BytesN<32>, i128> = Map::new(e);
+
+    for (tx_data, weight) in votes.iter() {
+        let hash: BytesN<32> = e.crypto().sha256(&tx_data).into();
+        let total = tally.get(hash.clone()).unwrap_or(0) + weight;
+
+        // A strict majority threshold can only ever be met by one attestation.
+        if total >= vote_threshold {
+            return Some(tx_data);
         }
+        tally.set(hash, total);
     }
 
     None // No consensus reached
 }
+
+// Weight behind the attestation with the most support, for reporting how close a transfer is.
+pub fn leading_weight(e: &Env, votes: &Vec<(Bytes, i128)>) -> i128 {
+    let mut tally: Map<BytesN<32>, i128> = Map::new(e);
+    let mut leading = 0;
+
+    for (tx_data, weight) in votes.iter() {
+        let hash: BytesN<32> = e.crypto().sha256(&tx_data).into();
+        let total = tally.get(hash.clone()).unwrap_or(0) + weight;
+        leading = i128::max(leading, total);
+        tally.set(hash, total);
+    }
+
+    leading
+}
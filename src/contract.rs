@@ -1,50 +1,126 @@
 use soroban_sdk::{
     contract, contractimpl, symbol_short,
     xdr::{FromXdr, ToXdr},
-    Address, Bytes, BytesN, Env, FromVal, String, Symbol, Val, Vec,
+    Address, Bytes, BytesN, Env, FromVal, Map, String, Symbol, Vec,
 };
 
 use crate::{
     access::{
-        authenticate_admin, has_admin, has_bridge_contract, read_admin, read_bridge_contract,
-        write_admin, write_bridge_contract,
+        authenticate_admin, authenticate_manager, clear_pending_admin, delete_manager, has_admin,
+        is_manager, read_admin, read_bridge_contract, read_guardian, read_managers,
+        read_pending_admin, write_admin, write_bridge_contract, write_guardian, write_manager,
+        write_pending_admin,
     },
     bridge_liquidity,
     chains::{
-        chain_is_supported, delete_chain, read_chain, read_supported_chain_list, write_chain,
+        chain_is_supported, delete_chain, read_chain, read_chain_signers,
+        read_supported_chain_list, source_signing_payload, verify_source_payload, write_chain,
+        write_chain_signers,
     },
-    consensus::{clear_consensus_count, read_consensus_threshold, threshold_validation},
+    consensus::{
+        is_valid_attestation, leading_weight, read_consensus_policy, read_consensus_quorum,
+        read_consensus_threshold, read_push_enabled, read_reveal_delay, read_vote_threshold,
+        threshold_validation, validate_consensus_policy, validate_consensus_quorum,
+        validate_reveal_delay, write_consensus_policy, write_consensus_quorum, write_push_enabled,
+        write_reveal_delay, ConsensusPolicy,
+    },
+    council::{
+        authenticate_council_member, count_approvals, ensure_not_governed, has_council,
+        is_council_member, read_council, read_proposal, read_quorum, validate_council,
+        write_council, write_new_proposal, write_proposal, write_quorum, Proposal, ProposalAction,
+    },
+    decode::decode_transfer_data,
     error::ContractError,
+    faults::{
+        is_jailed, read_faults, read_slashing_config, validate_slashing_config, write_faults,
+        write_jailed, write_slashing_config, SlashingConfig,
+    },
     oracle_receptacle::{self, create_receptacle},
+    pause::{
+        ensure_not_paused, ensure_route_active, read_chain_paused, read_paused,
+        read_token_chain_paused, write_chain_paused, write_paused, write_token_chain_paused,
+    },
     receptacle_ids::{
-        delete_receptacle, read_is_operator, read_receptacle_address, read_receptacle_list,
+        delete_receptacle, read_active_receptacle_list, read_is_operator, read_operator_list,
+        read_receptacle_address, read_receptacle_list, read_receptacle_operator,
         write_receptacle_address,
     },
+    rewards::{
+        fund_reward_pool, pay_keeper_reward, read_keeper_reward_config,
+        read_keeper_reward_config_or_err, read_reward_pool, validate_keeper_reward_config,
+        withdraw_reward_pool, write_keeper_reward_config, KeeperRewardConfig,
+    },
+    signers::{
+        delete_operator_key, read_key_operator, read_operator_key, signing_payload,
+        write_operator_key, OperatorSignature,
+    },
+    staking::{
+        deposit_bond, read_bond, read_bond_config, read_bond_config_or_err, read_slashed_stake,
+        read_total_stake, read_unbonding, read_vote_weight, slash_bond, start_unbonding,
+        validate_bond_config, withdraw_slashed_stake, withdraw_unbonding, write_bond_config,
+        BondConfig, Unbonding,
+    },
+    stats::{
+        read_operator_stats, read_operator_stats_page, record_attestation, record_late_attestation,
+        record_missed, OperatorStats,
+    },
     tokens::{
         delete_token_chain_map, read_destination_chain_token, read_destination_token_list,
         read_token_chain_is_supported, read_token_is_supported, write_token_chain_map,
     },
-    types::{ChainOracleDetails, DestinationChainDetails, ReceptacleDetails, TransferData},
+    transfers::{
+        clear_pending_votes, clear_transfer_failure, is_transfer_processed, read_missed_votes,
+        read_pending_votes, read_transfer_failure, write_missed_votes, write_pending_votes,
+        write_transfer_failure, write_transfer_processed,
+    },
+    types::{
+        ChainOracleDetails, DecodedTransferData, DestinationChainDetails, ReceptacleDetails,
+        SourceSignature, TransferData,
+    },
+    upgrade::{
+        clear_pending_upgrade, read_pending_upgrade, read_pending_upgrade_delay,
+        read_upgrade_delay, validate_upgrade_delay, write_pending_upgrade,
+        write_pending_upgrade_delay, write_upgrade_delay, PendingUpgrade, PendingUpgradeDelay,
+    },
+    upkeep::{
+        read_upkeep_config, validate_upkeep_config, write_upkeep_config, TransferVotes,
+        UpkeepCheck, UpkeepConfig, UpkeepOutcome, UpkeepSummary, MAX_LISTED_TRANSFERS,
+    },
 };
 
 const TRANSFER: Symbol = symbol_short!("TRANSFER");
+const ADMIN: Symbol = symbol_short!("ADMIN");
+const COUNCIL: Symbol = symbol_short!("COUNCIL");
+const UPGRADE: Symbol = symbol_short!("UPGRADE");
+const PAUSE: Symbol = symbol_short!("PAUSE");
+const CONSENSUS: Symbol = symbol_short!("CONSENSUS");
+const STAKING: Symbol = symbol_short!("STAKING");
+const UPKEEP: Symbol = symbol_short!("UPKEEP");
+const SLASHING: Symbol = symbol_short!("SLASHING");
+const CHAIN: Symbol = symbol_short!("CHAIN");
 
 pub trait OracleTrait {
     fn initialize(e: Env, admin: Address) -> Result<(), ContractError>;
     fn setup_new_node(e: Env, operator: Address) -> Result<Address, ContractError>;
-    fn setup_bridge(e: Env, bridge_contract: Address);
+    fn setup_bridge(e: Env, bridge_contract: Address) -> Result<(), ContractError>;
     fn remove_node(e: Env, operator: Address) -> Result<(), ContractError>;
 
+    fn add_manager(e: Env, manager: Address) -> Result<(), ContractError>;
+    fn remove_manager(e: Env, manager: Address) -> Result<(), ContractError>;
+    fn get_managers(e: Env) -> Vec<Address>;
+
     fn add_new_chain(
         e: Env,
+        manager: Address,
         chain_name: String,
         chain_id: u32,
         oracle_address: String,
     ) -> Result<(), ContractError>;
-    fn remove_chain(e: Env, chain_id: u32) -> Result<(), ContractError>;
+    fn remove_chain(e: Env, manager: Address, chain_id: u32) -> Result<(), ContractError>;
 
     fn add_token_destination_map(
         e: Env,
+        manager: Address,
         token_id: Address,
         chain_id: u32,
         destination_token: String,
@@ -52,9 +128,30 @@ pub trait OracleTrait {
 
     fn remove_token_destination_map(
         e: Env,
+        manager: Address,
+        token_id: Address,
+        chain_id: u32,
+    ) -> Result<(), ContractError>;
+
+    fn set_chain_active(
+        e: Env,
+        manager: Address,
+        chain_id: u32,
+        active: bool,
+    ) -> Result<(), ContractError>;
+    fn set_token_chain_active(
+        e: Env,
+        manager: Address,
         token_id: Address,
         chain_id: u32,
+        active: bool,
     ) -> Result<(), ContractError>;
+    fn get_chain_active(e: Env, chain_id: u32) -> Result<bool, ContractError>;
+    fn get_token_chain_active(
+        e: Env,
+        token_id: Address,
+        chain_id: u32,
+    ) -> Result<bool, ContractError>;
 
     fn get_destination_chain_token(
         e: Env,
@@ -67,7 +164,36 @@ pub trait OracleTrait {
         token_id: Address,
     ) -> Result<Vec<DestinationChainDetails>, ContractError>;
 
+    fn set_consensus_policy(e: Env, policy: ConsensusPolicy) -> Result<(), ContractError>;
+    fn get_consensus_policy(e: Env) -> ConsensusPolicy;
     fn get_consensus_threshold(e: Env) -> u32;
+    fn set_consensus_quorum(e: Env, quorum: u32) -> Result<(), ContractError>;
+    fn get_consensus_quorum(e: Env) -> u32;
+    fn get_vote_threshold(e: Env) -> i128;
+    fn set_reveal_delay(e: Env, reveal_delay: Option<u32>) -> Result<(), ContractError>;
+    fn get_reveal_delay(e: Env) -> Option<u32>;
+    fn set_push_enabled(e: Env, enabled: bool) -> Result<(), ContractError>;
+    fn get_push_enabled(e: Env) -> bool;
+
+    fn set_bond_config(e: Env, config: BondConfig) -> Result<(), ContractError>;
+    fn get_bond_config(e: Env) -> Option<BondConfig>;
+    fn bond(e: Env, operator: Address, amount: i128) -> Result<i128, ContractError>;
+    fn unbond(e: Env, operator: Address, amount: i128) -> Result<Unbonding, ContractError>;
+    fn withdraw_unbonded(e: Env, operator: Address) -> Result<i128, ContractError>;
+    fn get_bond(e: Env, operator: Address) -> i128;
+    fn get_unbonding(e: Env, operator: Address) -> Option<Unbonding>;
+    fn get_total_stake(e: Env) -> i128;
+
+    fn set_slashing_config(e: Env, config: SlashingConfig) -> Result<(), ContractError>;
+    fn get_slashing_config(e: Env) -> Option<SlashingConfig>;
+    fn unjail_operator(e: Env, operator: Address) -> Result<(), ContractError>;
+    fn get_faults(e: Env, operator: Address) -> u32;
+    fn get_operator_jailed(e: Env, operator: Address) -> bool;
+    fn withdraw_slashed(e: Env, to: Address, amount: i128) -> Result<(), ContractError>;
+    fn get_slashed_stake(e: Env) -> i128;
+
+    fn get_operator_stats(e: Env, operator: Address) -> Result<OperatorStats, ContractError>;
+    fn get_operator_stats_list(e: Env, start: u32, limit: u32) -> Vec<(Address, OperatorStats)>;
 
     fn initiate_outgoing_transfer(
         e: Env,
@@ -86,7 +212,72 @@ pub trait OracleTrait {
         amount: i128,
     ) -> Result<u64, ContractError>;
 
-    fn perform_upkeep(e: &Env);
+    fn perform_upkeep(e: &Env, keeper: Address) -> Result<UpkeepSummary, ContractError>;
+    fn perform_upkeep_for(
+        e: &Env,
+        keeper: Address,
+        tx_ids: Vec<Bytes>,
+    ) -> Result<Vec<(Bytes, UpkeepOutcome)>, ContractError>;
+    fn check_upkeep(e: &Env) -> UpkeepCheck;
+    fn purge_transfer(e: Env, manager: Address, tx_id: Bytes) -> Result<(), ContractError>;
+    fn set_upkeep_config(e: Env, config: UpkeepConfig) -> Result<(), ContractError>;
+    fn get_upkeep_config(e: Env) -> UpkeepConfig;
+    fn set_keeper_reward_config(e: Env, config: KeeperRewardConfig) -> Result<(), ContractError>;
+    fn get_keeper_reward_config(e: Env) -> Option<KeeperRewardConfig>;
+    fn fund_reward_pool(e: Env, amount: i128) -> Result<i128, ContractError>;
+    fn withdraw_reward_pool(e: Env, to: Address, amount: i128) -> Result<i128, ContractError>;
+    fn get_reward_pool(e: Env) -> i128;
+    fn set_operator_key(
+        e: Env,
+        operator: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), ContractError>;
+    fn get_operator_key(e: Env, operator: Address) -> Option<BytesN<32>>;
+    fn get_signing_payload(
+        e: Env,
+        tx_id: Bytes,
+        origin_chain: u32,
+        sender: String,
+        transfer_data: Bytes,
+    ) -> Result<Bytes, ContractError>;
+    fn submit_signed_transfer(
+        e: Env,
+        tx_id: Bytes,
+        origin_chain: u32,
+        sender: String,
+        transfer_data: Bytes,
+        signatures: Vec<OperatorSignature>,
+        source_signature: Option<SourceSignature>,
+    ) -> Result<(), ContractError>;
+    fn get_transfer_processed(e: Env, tx_id: Bytes) -> bool;
+    fn push_attestation(
+        e: Env,
+        receptacle: Address,
+        attestation: Vec<Bytes>,
+    ) -> Result<bool, ContractError>;
+    fn get_pending_votes(e: Env, tx_id: Bytes) -> Map<Address, BytesN<32>>;
+
+    fn set_chain_signers(
+        e: Env,
+        chain_id: u32,
+        signers: Vec<BytesN<20>>,
+    ) -> Result<(), ContractError>;
+    fn get_chain_signers(e: Env, chain_id: u32) -> Vec<BytesN<20>>;
+    fn get_source_signing_payload(
+        e: Env,
+        tx_id: Bytes,
+        origin_chain: u32,
+        sender: String,
+        transfer_data: Bytes,
+    ) -> Bytes;
+    fn verify_source_payload(
+        e: Env,
+        tx_id: Bytes,
+        origin_chain: u32,
+        sender: String,
+        transfer_data: Bytes,
+        signature: Option<SourceSignature>,
+    ) -> Result<(), ContractError>;
     fn get_upkeep_required(e: &Env) -> bool;
 
     fn get_receptacle_address(e: Env, operator: Address) -> Result<Address, ContractError>;
@@ -96,9 +287,38 @@ pub trait OracleTrait {
     fn get_all_supported_chains(e: Env) -> Vec<ChainOracleDetails>;
     // fn get_consensus_threshold(e: Env) -> u32;
 
-    fn get_admin(e: Env) -> Address;
-
-    fn upgrade(e: Env, new_wasm_hash: BytesN<32>);
+    fn get_admin(e: Env) -> Result<Address, ContractError>;
+
+    fn propose_admin(e: Env, new_admin: Address) -> Result<(), ContractError>;
+    fn accept_admin(e: Env) -> Result<(), ContractError>;
+    fn cancel_admin_proposal(e: Env) -> Result<(), ContractError>;
+    fn get_pending_admin(e: Env) -> Option<Address>;
+
+    fn set_guardian(e: Env, guardian: Address) -> Result<(), ContractError>;
+    fn get_guardian(e: Env) -> Option<Address>;
+    fn pause(e: Env) -> Result<(), ContractError>;
+    fn unpause(e: Env) -> Result<(), ContractError>;
+    fn get_pause_state(e: Env) -> bool;
+
+    fn announce_upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), ContractError>;
+    fn cancel_upgrade(e: Env) -> Result<(), ContractError>;
+    fn execute_upgrade(e: Env) -> Result<(), ContractError>;
+    fn set_upgrade_delay(e: Env, delay: u32) -> Result<(), ContractError>;
+    fn get_pending_upgrade(e: Env) -> Option<PendingUpgrade>;
+    fn get_upgrade_delay(e: Env) -> u32;
+    fn get_pending_upgrade_delay(e: Env) -> Option<PendingUpgradeDelay>;
+
+    fn setup_council(e: Env, members: Vec<Address>, quorum: u32) -> Result<(), ContractError>;
+    fn create_proposal(
+        e: Env,
+        proposer: Address,
+        action: ProposalAction,
+    ) -> Result<u64, ContractError>;
+    fn approve_proposal(e: Env, member: Address, proposal_id: u64) -> Result<(), ContractError>;
+    fn execute_proposal(e: Env, proposal_id: u64) -> Result<(), ContractError>;
+    fn get_proposal(e: Env, proposal_id: u64) -> Result<Proposal, ContractError>;
+    fn get_council(e: Env) -> Vec<Address>;
+    fn get_council_quorum(e: Env) -> u32;
 }
 
 #[contract]
@@ -116,107 +336,178 @@ impl OracleTrait for Oracle {
     }
 
     fn setup_new_node(e: Env, operator: Address) -> Result<Address, ContractError> {
-        authenticate_admin(&e);
+        authenticate_admin(&e)?;
+        ensure_not_governed(&e)?;
 
-        if read_is_operator(&e, operator.clone()) {
-            return Err(ContractError::AlreadyNodeOperator);
-        }
-        let receptacle_address = create_receptacle(&e, &operator.clone());
+        register_node(&e, operator)
+    }
 
-        let receptacle_contract = oracle_receptacle::Client::new(&e, &receptacle_address);
+    fn setup_bridge(e: Env, bridge_contract: Address) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
+        ensure_not_governed(&e)?;
+
+        write_bridge_contract(&e, &bridge_contract);
 
-        receptacle_contract.initialize(&e.current_contract_address(), &operator);
+        Ok(())
+    }
 
-        write_receptacle_address(&e, operator, receptacle_address.clone());
+    fn remove_node(e: Env, operator: Address) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
+        ensure_not_governed(&e)?;
 
-        Ok(receptacle_address)
+        deregister_node(&e, operator)
     }
 
-    fn setup_bridge(e: Env, bridge_contract: Address) {
-        authenticate_admin(&e);
+    fn add_manager(e: Env, manager: Address) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
 
-        write_bridge_contract(&e, &bridge_contract);
+        if is_manager(&e, &manager) {
+            return Err(ContractError::AlreadyManager);
+        }
+
+        write_manager(&e, &manager);
+
+        Ok(())
     }
 
-    fn remove_node(e: Env, operator: Address) -> Result<(), ContractError> {
-        authenticate_admin(&e);
+    fn remove_manager(e: Env, manager: Address) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
 
-        if !read_is_operator(&e, operator.clone()) {
-            return Err(ContractError::NodeNotFound);
+        if !is_manager(&e, &manager) {
+            return Err(ContractError::ManagerNotFound);
         }
 
-        delete_receptacle(&e, operator);
+        delete_manager(&e, &manager);
 
         Ok(())
     }
 
+    fn get_managers(e: Env) -> Vec<Address> {
+        read_managers(&e)
+    }
+
     fn add_new_chain(
         e: Env,
+        manager: Address,
         chain_name: String,
         chain_id: u32,
         oracle_address: String,
     ) -> Result<(), ContractError> {
-        authenticate_admin(&e);
+        authenticate_manager(&e, &manager)?;
+        ensure_not_governed(&e)?;
 
-        if chain_is_supported(&e, chain_id) {
-            return Err(ContractError::ChainAlreadyAdded);
-        }
+        register_chain(&e, chain_name, chain_id, oracle_address)
+    }
 
-        write_chain(&e, chain_name, chain_id, &oracle_address);
+    fn remove_chain(e: Env, manager: Address, chain_id: u32) -> Result<(), ContractError> {
+        authenticate_manager(&e, &manager)?;
+        ensure_not_governed(&e)?;
 
-        Ok(())
+        deregister_chain(&e, chain_id)
+    }
+
+    fn add_token_destination_map(
+        e: Env,
+        manager: Address,
+        token_id: Address,
+        chain_id: u32,
+        destination_token: String,
+    ) -> Result<(), ContractError> {
+        authenticate_manager(&e, &manager)?;
+
+        if !chain_is_supported(&e, chain_id) {
+            return Err(ContractError::ChainNotFound);
+        }
+
+        write_token_chain_map(&e, token_id, chain_id, destination_token)
     }
 
-    fn remove_chain(e: Env, chain_id: u32) -> Result<(), ContractError> {
-        authenticate_admin(&e);
+    fn remove_token_destination_map(
+        e: Env,
+        manager: Address,
+        token_id: Address,
+        chain_id: u32,
+    ) -> Result<(), ContractError> {
+        authenticate_manager(&e, &manager)?;
+
+        if !read_token_is_supported(&e, token_id.clone()) {
+            return Err(ContractError::TokenChainMapNotFound);
+        }
 
         if !chain_is_supported(&e, chain_id) {
             return Err(ContractError::ChainNotFound);
         }
-        delete_chain(&e, chain_id);
+
+        if !read_token_chain_is_supported(&e, token_id.clone(), chain_id) {
+            return Err(ContractError::TokenChainMapNotFound);
+        }
+
+        delete_token_chain_map(&e, token_id, chain_id);
 
         Ok(())
     }
 
-    fn add_token_destination_map(
+    fn set_chain_active(
         e: Env,
-        token_id: Address,
+        manager: Address,
         chain_id: u32,
-        destination_token: String,
+        active: bool,
     ) -> Result<(), ContractError> {
-        authenticate_admin(&e);
+        authenticate_manager(&e, &manager)?;
 
         if !chain_is_supported(&e, chain_id) {
             return Err(ContractError::ChainNotFound);
         }
 
-        write_token_chain_map(&e, token_id, chain_id, destination_token);
+        write_chain_paused(&e, chain_id, !active);
+
+        e.events()
+            .publish((PAUSE, symbol_short!("chain")), (chain_id, active));
 
         Ok(())
     }
 
-    fn remove_token_destination_map(
+    fn set_token_chain_active(
         e: Env,
+        manager: Address,
         token_id: Address,
         chain_id: u32,
+        active: bool,
     ) -> Result<(), ContractError> {
-        authenticate_admin(&e);
+        authenticate_manager(&e, &manager)?;
 
-        if !read_token_is_supported(&e, token_id.clone()) {
-            return Err(ContractError::TokenNotSupported);
+        if !read_token_chain_is_supported(&e, token_id.clone(), chain_id) {
+            return Err(ContractError::TokenChainMapNotFound);
         }
 
-        if !chain_is_supported(&e, chain_id.clone()) {
+        write_token_chain_paused(&e, token_id.clone(), chain_id, !active);
+
+        e.events().publish(
+            (PAUSE, symbol_short!("route")),
+            (token_id, chain_id, active),
+        );
+
+        Ok(())
+    }
+
+    fn get_chain_active(e: Env, chain_id: u32) -> Result<bool, ContractError> {
+        if !chain_is_supported(&e, chain_id) {
             return Err(ContractError::ChainNotFound);
         }
 
-        if !read_token_chain_is_supported(&e, token_id.clone(), chain_id.clone()) {
+        Ok(!read_chain_paused(&e, chain_id))
+    }
+
+    fn get_token_chain_active(
+        e: Env,
+        token_id: Address,
+        chain_id: u32,
+    ) -> Result<bool, ContractError> {
+        if !read_token_chain_is_supported(&e, token_id.clone(), chain_id) {
             return Err(ContractError::TokenChainMapNotFound);
         }
 
-        delete_token_chain_map(&e, token_id, chain_id);
-
-        Ok(())
+        Ok(!read_token_chain_paused(&e, token_id, chain_id))
     }
 
     fn get_destination_chain_token(
@@ -225,21 +516,18 @@ impl OracleTrait for Oracle {
         chain_id: u32,
     ) -> Result<DestinationChainDetails, ContractError> {
         if !read_token_is_supported(&e, token_id.clone()) {
-            return Err(ContractError::TokenNotSupported);
+            return Err(ContractError::TokenChainMapNotFound);
         }
 
-        if !chain_is_supported(&e, chain_id.clone()) {
+        if !chain_is_supported(&e, chain_id) {
             return Err(ContractError::ChainNotFound);
         }
 
-        if !read_token_chain_is_supported(&e, token_id.clone(), chain_id.clone()) {
+        if !read_token_chain_is_supported(&e, token_id.clone(), chain_id) {
             return Err(ContractError::TokenChainMapNotFound);
         }
 
-        let details: DestinationChainDetails =
-            read_destination_chain_token(&e, token_id, chain_id).unwrap();
-
-        Ok(details)
+        read_destination_chain_token(&e, token_id, chain_id)
     }
 
     fn get_destination_chain_list(
@@ -247,7 +535,7 @@ impl OracleTrait for Oracle {
         token_id: Address,
     ) -> Result<Vec<DestinationChainDetails>, ContractError> {
         if !read_token_is_supported(&e, token_id.clone()) {
-            return Err(ContractError::TokenNotSupported);
+            return Err(ContractError::TokenChainMapNotFound);
         }
 
         let list: Vec<DestinationChainDetails> = read_destination_token_list(&e, token_id);
@@ -264,38 +552,39 @@ impl OracleTrait for Oracle {
         amount: i128,
     ) -> Result<u64, ContractError> {
         user.require_auth();
-        if !has_bridge_contract(&e) {
-            return Err(ContractError::BridgeNotFound);
-        }
+        ensure_not_paused(&e)?;
+
+        let bridge_contract = read_bridge_contract(&e)?;
 
         if !read_token_is_supported(&e, token_id.clone()) {
-            return Err(ContractError::TokenNotSupported);
+            return Err(ContractError::TokenChainMapNotFound);
         }
 
-        if !chain_is_supported(&e, chain_id.clone()) {
+        if !chain_is_supported(&e, chain_id) {
             return Err(ContractError::ChainNotFound);
         }
 
-        if !read_token_chain_is_supported(&e, token_id.clone(), chain_id.clone()) {
+        if !read_token_chain_is_supported(&e, token_id.clone(), chain_id) {
             return Err(ContractError::TokenChainMapNotFound);
         }
 
-        let bridge_client = bridge_liquidity::Client::new(&e, &read_bridge_contract(&e).unwrap());
+        ensure_route_active(&e, token_id.clone(), chain_id)?;
+
+        let bridge_client = bridge_liquidity::Client::new(&e, &bridge_contract);
 
         bridge_client.transfer_soroban_to_evm(&user, &token_id, &amount);
 
         let tx_id: u64 = e.prng().gen();
 
-        let destination_token = read_destination_chain_token(&e, token_id, chain_id)
-            .unwrap()
-            .destination_token;
+        let destination_token =
+            read_destination_chain_token(&e, token_id, chain_id)?.destination_token;
 
-        let chain_oracle = read_chain(&e, chain_id).unwrap().oracle_address;
+        let chain_oracle = read_chain(&e, chain_id)?.oracle_address;
 
         let data = TransferData {
-            recipient: recipient,
+            recipient,
             token: destination_token,
-            amount: amount,
+            amount,
         };
 
         e.events().publish(
@@ -315,38 +604,39 @@ impl OracleTrait for Oracle {
         amount: i128,
     ) -> Result<u64, ContractError> {
         user.require_auth();
-        if !has_bridge_contract(&e) {
-            return Err(ContractError::BridgeNotFound);
-        }
+        ensure_not_paused(&e)?;
+
+        let bridge_contract = read_bridge_contract(&e)?;
 
         if !read_token_is_supported(&e, token_id.clone()) {
-            return Err(ContractError::TokenNotSupported);
+            return Err(ContractError::TokenChainMapNotFound);
         }
 
-        if !chain_is_supported(&e, chain_id.clone()) {
+        if !chain_is_supported(&e, chain_id) {
             return Err(ContractError::ChainNotFound);
         }
 
-        if !read_token_chain_is_supported(&e, token_id.clone(), chain_id.clone()) {
+        if !read_token_chain_is_supported(&e, token_id.clone(), chain_id) {
             return Err(ContractError::TokenChainMapNotFound);
         }
 
-        let bridge_client = bridge_liquidity::Client::new(&e, &read_bridge_contract(&e).unwrap());
+        ensure_route_active(&e, token_id.clone(), chain_id)?;
+
+        let bridge_client = bridge_liquidity::Client::new(&e, &bridge_contract);
 
         bridge_client.execute_rebalancing(&user, &token_id, &amount);
 
         let tx_id: u64 = e.prng().gen();
 
-        let destination_token = read_destination_chain_token(&e, token_id, chain_id)
-            .unwrap()
-            .destination_token;
+        let destination_token =
+            read_destination_chain_token(&e, token_id, chain_id)?.destination_token;
 
-        let chain_oracle = read_chain(&e, chain_id).unwrap().oracle_address;
+        let chain_oracle = read_chain(&e, chain_id)?.oracle_address;
 
         let data = TransferData {
-            recipient: recipient,
+            recipient,
             token: destination_token,
-            amount: amount,
+            amount,
         };
 
         e.events().publish(
@@ -357,112 +647,474 @@ impl OracleTrait for Oracle {
         Ok(tx_id)
     }
 
-    fn perform_upkeep(e: &Env) {
-        let all_receptacles: Vec<ReceptacleDetails> = read_receptacle_list(e);
+    // Anyone may run upkeep; the keeper address passed in collects the reward.
+    fn perform_upkeep(e: &Env, keeper: Address) -> Result<UpkeepSummary, ContractError> {
+        ensure_not_paused(e)?;
+
+        let all_receptacles: Vec<ReceptacleDetails> = read_active_receptacle_list(e);
+
+        if all_receptacles.is_empty() {
+            return Err(ContractError::NoReceptacles);
+        }
+
+        let mut reads: u32 = 0;
+        let tx_id_list = read_pending_transfer_ids(e, &all_receptacles, &mut reads);
+
+        let config = read_upkeep_config(e);
+        let mut summary = UpkeepSummary {
+            processed: 0,
+            skipped: 0,
+            failed: 0,
+            remaining: 0,
+            reward: 0,
+        };
+        let mut executed: u32 = 0;
+        let mut examined: u32 = 0;
+
+        let reveal_delay = read_reveal_delay(e);
+        let mut weights = read_vote_weights(e, &all_receptacles);
+
+        for tx_id in tx_id_list.iter() {
+            // The first transfer is always examined so a small read budget cannot stall upkeep.
+            // Executing a transfer also removes it from every receptacle. Transfers that stay queued
+            // do not take a slot, so a few stuck ones at the head cannot hold back the rest.
+            let voter_count = weights.iter().filter(|weight| *weight > 0).count() as u32;
+            let max_reads = if reveal_delay.is_some() {
+                voter_count * 2
+            } else {
+                voter_count
+            } + all_receptacles.len();
+            if summary.processed == config.max_transfers
+                || (examined > 0 && reads + max_reads > config.max_receptacle_reads)
+            {
+                break;
+            }
+            examined += 1;
+
+            let outcome = process_transfer(
+                e,
+                &all_receptacles,
+                &mut weights,
+                reveal_delay,
+                &tx_id,
+                &mut reads,
+            )?;
+            match outcome {
+                UpkeepOutcome::Executed => {
+                    executed += 1;
+                    summary.processed += 1;
+                }
+                UpkeepOutcome::AlreadyProcessed => summary.processed += 1,
+                UpkeepOutcome::Failed => summary.failed += 1,
+                _ => summary.skipped += 1,
+            }
+        }
+        summary.remaining = tx_id_list.len() - examined;
+        summary.reward = reward_keeper(e, &keeper, executed);
+
+        Ok(summary)
+    }
+
+    // The caller picks the transfers, so the upkeep config limits do not apply.
+    fn perform_upkeep_for(
+        e: &Env,
+        keeper: Address,
+        tx_ids: Vec<Bytes>,
+    ) -> Result<Vec<(Bytes, UpkeepOutcome)>, ContractError> {
+        ensure_not_paused(e)?;
+
+        let all_receptacles: Vec<ReceptacleDetails> = read_active_receptacle_list(e);
+
+        if all_receptacles.is_empty() {
+            return Err(ContractError::NoReceptacles);
+        }
+
+        let reveal_delay = read_reveal_delay(e);
+        let mut weights = read_vote_weights(e, &all_receptacles);
+        let mut reads: u32 = 0;
+        let mut executed: u32 = 0;
+
+        let mut outcomes: Vec<(Bytes, UpkeepOutcome)> = Vec::new(e);
+        for tx_id in tx_ids.iter() {
+            let outcome = process_transfer(
+                e,
+                &all_receptacles,
+                &mut weights,
+                reveal_delay,
+                &tx_id,
+                &mut reads,
+            )?;
+            if outcome == UpkeepOutcome::Executed {
+                executed += 1;
+            }
+            outcomes.push_back((tx_id, outcome));
+        }
+        reward_keeper(e, &keeper, executed);
+
+        Ok(outcomes)
+    }
+
+    // Simulates the tally over every pending transfer. Ids that would execute, or only need
+    // clearing from receptacles, become perform data for `perform_upkeep_for`. Ids that failed to
+    // execute are left out so a keeper does not retry them on every check; the regular upkeep
+    // still does.
+    fn check_upkeep(e: &Env) -> UpkeepCheck {
+        let all_receptacles: Vec<ReceptacleDetails> = read_active_receptacle_list(e);
+        let config = read_upkeep_config(e);
+        let reveal_delay = read_reveal_delay(e);
+        let weights = read_vote_weights(e, &all_receptacles);
+        let mut reads: u32 = 0;
+
+        let mut perform_data: Vec<Bytes> = Vec::new(e);
+        let mut transfers: Vec<TransferVotes> = Vec::new(e);
+        for tx_id in read_pending_transfer_ids(e, &all_receptacles, &mut reads).iter() {
+            let last_failure = read_transfer_failure(e, &tx_id);
+            let ready = if is_transfer_processed(e, &tx_id) {
+                true
+            } else {
+                let (votes, _, _) = collect_votes(
+                    e,
+                    &all_receptacles,
+                    &weights,
+                    reveal_delay,
+                    &tx_id,
+                    &mut reads,
+                );
+                transfers.push_back(TransferVotes {
+                    tx_id: tx_id.clone(),
+                    votes: votes.len(),
+                    leading_weight: leading_weight(e, &votes),
+                    last_failure,
+                });
+                threshold_validation(e, votes)
+                    .and_then(|validated| Vec::<Bytes>::from_xdr(e, &validated).ok())
+                    .and_then(|validated| decode_attestation(e, &validated).ok())
+                    .is_some_and(|(origin_chain, decoded)| {
+                        ensure_route_active(e, decoded.token, origin_chain).is_ok()
+                    })
+            };
+
+            // Perform data is capped so a single upkeep can get through all of it.
+            if ready && last_failure.is_none() && perform_data.len() < config.max_transfers {
+                perform_data.push_back(tx_id);
+            }
+        }
 
-        if all_receptacles.len() == 0 {
-            panic!("No receptacles found")
+        UpkeepCheck {
+            upkeep_needed: !perform_data.is_empty() && !read_paused(e),
+            perform_data,
+            vote_threshold: read_vote_threshold(e),
+            transfers,
         }
+    }
 
-        // let length: u64 = all_receptacles.len() as u64;
-        // let seeder_node_index: u64 = e.prng().gen_range(0..length);
+    fn purge_transfer(e: Env, manager: Address, tx_id: Bytes) -> Result<(), ContractError> {
+        authenticate_manager(&e, &manager)?;
+        ensure_not_governed(&e)?;
 
-        let seeder_node_address: Address =
-            all_receptacles.get_unchecked(0 as u32).receptacle_address;
+        purge_transfer(&e, tx_id)
+    }
 
-        let receptacle_contract = oracle_receptacle::Client::new(&e, &seeder_node_address);
+    fn set_upkeep_config(e: Env, config: UpkeepConfig) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
+        ensure_not_governed(&e)?;
 
-        let tx_ids_val: Val = receptacle_contract.get_transfer_id_list();
-        let tx_id_list: Vec<Bytes> = Vec::from_val(e, &tx_ids_val);
+        set_upkeep_config(&e, config)
+    }
 
-        // if tx_id_list.len() == 0 {
-        //     panic!("No transaction found");
-        // }
+    fn get_upkeep_config(e: Env) -> UpkeepConfig {
+        read_upkeep_config(&e)
+    }
 
-        if tx_id_list.len() > 0 {
-            let mut data_list_reset: Vec<Bytes> = Vec::new(e);
+    fn set_keeper_reward_config(e: Env, config: KeeperRewardConfig) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
+        ensure_not_governed(&e)?;
 
-            let limit = u32::min(tx_id_list.len(), 5);
+        set_keeper_reward_config(&e, config)
+    }
 
-            for i in 0..limit {
-                let mut data_list: Vec<Bytes> = Vec::new(e);
+    fn get_keeper_reward_config(e: Env) -> Option<KeeperRewardConfig> {
+        read_keeper_reward_config(&e)
+    }
 
-                let tx_id = tx_id_list.get_unchecked(i);
-                for j in 0..all_receptacles.len() {
-                    let receptacle_addr = all_receptacles.get_unchecked(j).receptacle_address;
-                    let receptacle_contract = oracle_receptacle::Client::new(&e, &receptacle_addr);
-                    let tx_data_val = receptacle_contract.get_transfer(&tx_id);
-                    let tx_data_array: Vec<Bytes> = Vec::from_val(e, &tx_data_val);
-                    let data_bytes = tx_data_array.clone().to_xdr(&e);
-                    data_list.push_back(data_bytes.clone());
-                    data_list_reset.push_back(data_bytes.clone());
-                }
+    fn fund_reward_pool(e: Env, amount: i128) -> Result<i128, ContractError> {
+        authenticate_admin(&e)?;
+        ensure_not_governed(&e)?;
 
-                if let Some(validated_data_bytes) = threshold_validation(e, data_list.clone()) {
-                    let default: Vec<Bytes> = Vec::new(&e);
-                    let validated_data_array: Vec<Bytes> =
-                        Vec::from_xdr(&e, &validated_data_bytes).unwrap_or(default);
-
-                    if validated_data_array.len() > 5 {
-                        let tx_id = validated_data_array.get_unchecked(0);
-
-                        let recipient =
-                            Address::from_xdr(e, &validated_data_array.get_unchecked(3))
-                                .ok()
-                                .unwrap();
-                        let token = Address::from_xdr(e, &validated_data_array.get_unchecked(4))
-                            .ok()
-                            .unwrap();
-                        let amount = i128::from_xdr(e, &validated_data_array.get_unchecked(5))
-                            .ok()
-                            .unwrap();
-
-                        let bridge_client =
-                            bridge_liquidity::Client::new(&e, &read_bridge_contract(e).unwrap());
-
-                        bridge_client.execute_evm_to_soroban_transfer(&recipient, &token, &amount);
-
-                        for j in 0..all_receptacles.len() {
-                            let receptacle_addr =
-                                all_receptacles.get_unchecked(j).receptacle_address;
-                            let receptacle_contract =
-                                oracle_receptacle::Client::new(&e, &receptacle_addr);
-                            receptacle_contract.remove_transfer(&tx_id);
-                        }
-                    }
-                };
+        fund_rewards(&e, read_admin(&e)?, amount)
+    }
 
-                for i in 0..data_list_reset.len() {
-                    let tx_data = data_list_reset.get_unchecked(i);
+    fn withdraw_reward_pool(e: Env, to: Address, amount: i128) -> Result<i128, ContractError> {
+        authenticate_admin(&e)?;
+        ensure_not_governed(&e)?;
 
-                    clear_consensus_count(e, tx_data);
-                }
+        withdraw_rewards(&e, to, amount)
+    }
+
+    fn get_reward_pool(e: Env) -> i128 {
+        read_reward_pool(&e)
+    }
+
+    fn set_operator_key(
+        e: Env,
+        operator: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        operator.require_auth();
+
+        if !read_is_operator(&e, operator.clone()) {
+            return Err(ContractError::NodeNotFound);
+        }
+
+        write_operator_key(&e, &operator, &public_key)
+    }
+
+    fn get_operator_key(e: Env, operator: Address) -> Option<BytesN<32>> {
+        read_operator_key(&e, &operator)
+    }
+
+    fn get_signing_payload(
+        e: Env,
+        tx_id: Bytes,
+        origin_chain: u32,
+        sender: String,
+        transfer_data: Bytes,
+    ) -> Result<Bytes, ContractError> {
+        let (_, attestation) = build_attestation(&e, tx_id, origin_chain, sender, transfer_data)?;
+        Ok(signing_payload(&e, &attestation))
+    }
+
+    fn submit_signed_transfer(
+        e: Env,
+        tx_id: Bytes,
+        origin_chain: u32,
+        sender: String,
+        transfer_data: Bytes,
+        signatures: Vec<OperatorSignature>,
+        source_signature: Option<SourceSignature>,
+    ) -> Result<(), ContractError> {
+        ensure_not_paused(&e)?;
+
+        if is_transfer_processed(&e, &tx_id) {
+            return Err(ContractError::TransferAlreadyProcessed);
+        }
+
+        // Decoding first turns a malformed payload into an error before any signature is checked.
+        let (decoded, attestation) = build_attestation(
+            &e,
+            tx_id.clone(),
+            origin_chain,
+            sender.clone(),
+            transfer_data.clone(),
+        )?;
+        verify_source_payload(
+            &e,
+            &tx_id,
+            origin_chain,
+            &sender,
+            &transfer_data,
+            source_signature,
+        )?;
+        ensure_route_active(&e, decoded.token.clone(), origin_chain)?;
+
+        // Unknown, jailed and repeated signers are ignored. The host has no fallible ed25519 check,
+        // so a bad signature from a counted signer traps the whole call.
+        let payload = signing_payload(&e, &attestation);
+        let mut signers: Vec<Address> = Vec::new(&e);
+        let mut votes: Vec<(Bytes, i128)> = Vec::new(&e);
+        for signature in signatures.iter() {
+            let Some(operator) = read_key_operator(&e, &signature.public_key) else {
+                continue;
+            };
+            if signers.contains(&operator) || is_jailed(&e, &operator) {
+                continue;
+            }
+            let weight = read_vote_weight(&e, &operator);
+            if weight == 0 {
+                continue;
             }
+
+            e.crypto()
+                .ed25519_verify(&signature.public_key, &payload, &signature.signature);
+
+            signers.push_back(operator);
+            votes.push_back((attestation.clone(), weight));
+        }
+
+        if threshold_validation(&e, votes).is_none() {
+            return Err(ContractError::QuorumNotReached);
         }
+
+        settle_transfer(&e, &tx_id, &decoded)?;
+
+        e.events()
+            .publish((TRANSFER, symbol_short!("signed")), (tx_id, signers));
+
+        Ok(())
     }
 
-    fn get_upkeep_required(e: &Env) -> bool {
-        let all_receptacles: Vec<ReceptacleDetails> = read_receptacle_list(e);
+    fn get_transfer_processed(e: Env, tx_id: Bytes) -> bool {
+        is_transfer_processed(&e, &tx_id)
+    }
 
-        if all_receptacles.is_empty() {
-            return false;
+    // Called by a receptacle as its operator commits, so a transfer can execute on the commit
+    // that reaches the threshold. Returns whether the transfer has been executed.
+    fn push_attestation(
+        e: Env,
+        receptacle: Address,
+        attestation: Vec<Bytes>,
+    ) -> Result<bool, ContractError> {
+        receptacle.require_auth();
+
+        let operator =
+            read_receptacle_operator(&e, &receptacle).ok_or(ContractError::ReceptacleNotFound)?;
+        if attestation.len() != 6 {
+            return Err(ContractError::MalformedTransferData);
+        }
+
+        let tx_id = attestation.get_unchecked(0);
+        let hash: BytesN<32> = e.crypto().sha256(&attestation.clone().to_xdr(&e)).into();
+        if is_transfer_processed(&e, &tx_id) {
+            // Once the data is public after execution a late reveal proves nothing, so commit-reveal
+            // mode keeps the miss.
+            if read_reveal_delay(&e).is_none() {
+                credit_late_vote(&e, &tx_id, &operator, &hash)?;
+            }
+            return Ok(true);
+        }
+        // Commit-reveal votes are only tallied by upkeep, where commitment ledgers can be checked.
+        if read_paused(&e)
+            || !read_push_enabled(&e)
+            || read_reveal_delay(&e).is_some()
+            || is_jailed(&e, &operator)
+        {
+            return Ok(false);
+        }
+
+        let mut pending_votes = read_pending_votes(&e, &tx_id);
+        // Votes from operators removed since they pushed no longer count.
+        for voter in pending_votes.keys().iter() {
+            if !read_is_operator(&e, voter.clone()) {
+                pending_votes.remove(voter);
+            }
+        }
+        pending_votes.set(operator, hash.clone());
+
+        let mut votes: Vec<(Bytes, i128)> = Vec::new(&e);
+        for (voter, vote) in pending_votes.iter() {
+            if is_jailed(&e, &voter) {
+                continue;
+            }
+            let weight = read_vote_weight(&e, &voter);
+            if weight > 0 {
+                votes.push_back((vote.into(), weight));
+            }
+        }
+
+        let winner = threshold_validation(&e, votes);
+        let (origin_chain, decoded) = decode_attestation(&e, &attestation)?;
+        if winner != Some(hash.clone().into())
+            || ensure_route_active(&e, decoded.token.clone(), origin_chain).is_err()
+        {
+            write_pending_votes(&e, &tx_id, &pending_votes);
+            return Ok(false);
+        }
+
+        settle_transfer(&e, &tx_id, &decoded)?;
+
+        // Jailed operators' votes did not count towards the result, so they are neither credited
+        // nor faulted.
+        let mut agreed_voters: Vec<Address> = Vec::new(&e);
+        for (voter, vote) in pending_votes.iter() {
+            if is_jailed(&e, &voter) {
+                continue;
+            }
+            let agreed = vote == hash;
+            record_attestation(&e, &voter, agreed);
+            if agreed {
+                agreed_voters.push_back(voter);
+            } else {
+                record_fault(&e, &voter, &tx_id)?;
+            }
+        }
+        let active_receptacles = read_active_receptacle_list(&e);
+        let mut missed: Vec<Address> = Vec::new(&e);
+        for details in active_receptacles.iter() {
+            if !pending_votes.contains_key(details.operator.clone()) {
+                record_missed(&e, &details.operator);
+                missed.push_back(details.operator);
+            }
         }
+        write_missed_votes(&e, &tx_id, &hash, &missed);
+
+        // Earlier voters still queue the transfer. The pushing receptacle drops it itself once
+        // this returns, and cannot be called back into while it waits on this call.
+        for details in active_receptacles.iter() {
+            if details.receptacle_address != receptacle {
+                oracle_receptacle::Client::new(&e, &details.receptacle_address)
+                    .remove_transfer(&tx_id);
+            }
+        }
+
+        e.events()
+            .publish((TRANSFER, symbol_short!("pushed")), (tx_id, agreed_voters));
+
+        Ok(true)
+    }
+
+    fn get_pending_votes(e: Env, tx_id: Bytes) -> Map<Address, BytesN<32>> {
+        read_pending_votes(&e, &tx_id)
+    }
+
+    fn set_chain_signers(
+        e: Env,
+        chain_id: u32,
+        signers: Vec<BytesN<20>>,
+    ) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
+        ensure_not_governed(&e)?;
+
+        set_chain_signers(&e, chain_id, signers)
+    }
 
-        let seeder_node_address: Address = all_receptacles.get_unchecked(0).receptacle_address;
+    fn get_chain_signers(e: Env, chain_id: u32) -> Vec<BytesN<20>> {
+        read_chain_signers(&e, chain_id)
+    }
 
-        let receptacle_contract = oracle_receptacle::Client::new(&e, &seeder_node_address);
+    fn get_source_signing_payload(
+        e: Env,
+        tx_id: Bytes,
+        origin_chain: u32,
+        sender: String,
+        transfer_data: Bytes,
+    ) -> Bytes {
+        source_signing_payload(&e, &tx_id, origin_chain, &sender, &transfer_data)
+    }
 
-        let tx_ids_val: Val = receptacle_contract.get_transfer_id_list();
-        let tx_id_list: Vec<Bytes> = Vec::from_val(e, &tx_ids_val);
+    fn verify_source_payload(
+        e: Env,
+        tx_id: Bytes,
+        origin_chain: u32,
+        sender: String,
+        transfer_data: Bytes,
+        signature: Option<SourceSignature>,
+    ) -> Result<(), ContractError> {
+        verify_source_payload(&e, &tx_id, origin_chain, &sender, &transfer_data, signature)
+    }
 
-        !tx_id_list.is_empty()
+    fn get_upkeep_required(e: &Env) -> bool {
+        read_active_receptacle_list(e).iter().any(|receptacle| {
+            let receptacle_contract =
+                oracle_receptacle::Client::new(e, &receptacle.receptacle_address);
+            !receptacle_contract.get_pending_transfers(&0, &1).is_empty()
+        })
     }
 
     fn get_receptacle_address(e: Env, operator: Address) -> Result<Address, ContractError> {
         if let Some(receptacle_address) = read_receptacle_address(&e, operator) {
             Ok(receptacle_address)
         } else {
-            return Err(ContractError::ReceptacleNotFound);
+            Err(ContractError::ReceptacleNotFound)
         }
     }
 
@@ -474,17 +1126,1197 @@ impl OracleTrait for Oracle {
         read_supported_chain_list(&e)
     }
 
+    fn set_consensus_policy(e: Env, policy: ConsensusPolicy) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
+        ensure_not_governed(&e)?;
+
+        set_consensus_policy(&e, policy)
+    }
+
+    fn get_consensus_policy(e: Env) -> ConsensusPolicy {
+        read_consensus_policy(&e)
+    }
+
     fn get_consensus_threshold(e: Env) -> u32 {
         read_consensus_threshold(&e)
     }
 
-    fn get_admin(e: Env) -> Address {
-        let admin = read_admin(&e).unwrap();
-        admin
+    fn set_consensus_quorum(e: Env, quorum: u32) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
+        ensure_not_governed(&e)?;
+
+        set_consensus_quorum(&e, quorum)
+    }
+
+    fn get_consensus_quorum(e: Env) -> u32 {
+        read_consensus_quorum(&e)
+    }
+
+    fn get_vote_threshold(e: Env) -> i128 {
+        read_vote_threshold(&e)
+    }
+
+    fn set_reveal_delay(e: Env, reveal_delay: Option<u32>) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
+        ensure_not_governed(&e)?;
+
+        set_reveal_delay(&e, reveal_delay)
+    }
+
+    fn get_reveal_delay(e: Env) -> Option<u32> {
+        read_reveal_delay(&e)
+    }
+
+    fn set_push_enabled(e: Env, enabled: bool) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
+        ensure_not_governed(&e)?;
+
+        set_push_enabled(&e, enabled);
+        Ok(())
     }
 
-    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) {
-        authenticate_admin(&e);
-        e.deployer().update_current_contract_wasm(new_wasm_hash);
+    fn get_push_enabled(e: Env) -> bool {
+        read_push_enabled(&e)
     }
+
+    fn set_bond_config(e: Env, config: BondConfig) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
+        ensure_not_governed(&e)?;
+
+        set_bond_config(&e, config)
+    }
+
+    fn get_bond_config(e: Env) -> Option<BondConfig> {
+        read_bond_config(&e)
+    }
+
+    fn bond(e: Env, operator: Address, amount: i128) -> Result<i128, ContractError> {
+        operator.require_auth();
+
+        let config = read_bond_config_or_err(&e)?;
+        if !read_is_operator(&e, operator.clone()) {
+            return Err(ContractError::NodeNotFound);
+        }
+        if is_jailed(&e, &operator) {
+            return Err(ContractError::OperatorJailed);
+        }
+
+        let bond = deposit_bond(&e, &config, &operator, amount)?;
+
+        e.events()
+            .publish((STAKING, symbol_short!("bond")), (operator, amount));
+
+        Ok(bond)
+    }
+
+    fn unbond(e: Env, operator: Address, amount: i128) -> Result<Unbonding, ContractError> {
+        operator.require_auth();
+
+        let config = read_bond_config_or_err(&e)?;
+        let unbonding = start_unbonding(&e, &config, &operator, amount)?;
+
+        e.events()
+            .publish((STAKING, symbol_short!("unbond")), (operator, amount));
+
+        Ok(unbonding)
+    }
+
+    fn withdraw_unbonded(e: Env, operator: Address) -> Result<i128, ContractError> {
+        operator.require_auth();
+
+        let config = read_bond_config_or_err(&e)?;
+        let amount = withdraw_unbonding(&e, &config, &operator)?;
+
+        e.events()
+            .publish((STAKING, symbol_short!("withdraw")), (operator, amount));
+
+        Ok(amount)
+    }
+
+    fn get_bond(e: Env, operator: Address) -> i128 {
+        read_bond(&e, &operator)
+    }
+
+    fn get_unbonding(e: Env, operator: Address) -> Option<Unbonding> {
+        read_unbonding(&e, &operator)
+    }
+
+    fn get_total_stake(e: Env) -> i128 {
+        read_total_stake(&e)
+    }
+
+    fn set_slashing_config(e: Env, config: SlashingConfig) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
+        ensure_not_governed(&e)?;
+
+        set_slashing_config(&e, config)
+    }
+
+    fn get_slashing_config(e: Env) -> Option<SlashingConfig> {
+        read_slashing_config(&e)
+    }
+
+    fn unjail_operator(e: Env, operator: Address) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
+        ensure_not_governed(&e)?;
+
+        unjail(&e, operator)
+    }
+
+    fn get_faults(e: Env, operator: Address) -> u32 {
+        read_faults(&e, &operator)
+    }
+
+    fn get_operator_jailed(e: Env, operator: Address) -> bool {
+        is_jailed(&e, &operator)
+    }
+
+    fn withdraw_slashed(e: Env, to: Address, amount: i128) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
+        ensure_not_governed(&e)?;
+
+        let config = read_bond_config_or_err(&e)?;
+        withdraw_slashed_stake(&e, &config, &to, amount)
+    }
+
+    fn get_slashed_stake(e: Env) -> i128 {
+        read_slashed_stake(&e)
+    }
+
+    fn get_operator_stats(e: Env, operator: Address) -> Result<OperatorStats, ContractError> {
+        if !read_is_operator(&e, operator.clone()) {
+            return Err(ContractError::NodeNotFound);
+        }
+        Ok(read_operator_stats(&e, &operator))
+    }
+
+    fn get_operator_stats_list(e: Env, start: u32, limit: u32) -> Vec<(Address, OperatorStats)> {
+        read_operator_stats_page(&e, start, limit)
+    }
+
+    fn get_admin(e: Env) -> Result<Address, ContractError> {
+        read_admin(&e)
+    }
+
+    fn propose_admin(e: Env, new_admin: Address) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
+
+        write_pending_admin(&e, &new_admin);
+
+        e.events().publish(
+            (ADMIN, symbol_short!("proposed")),
+            (read_admin(&e)?, new_admin),
+        );
+
+        Ok(())
+    }
+
+    fn accept_admin(e: Env) -> Result<(), ContractError> {
+        let new_admin = match read_pending_admin(&e) {
+            Some(pending_admin) => pending_admin,
+            None => return Err(ContractError::NoPendingAdmin),
+        };
+        new_admin.require_auth();
+
+        let old_admin = read_admin(&e)?;
+        write_admin(&e, &new_admin);
+        clear_pending_admin(&e);
+
+        e.events()
+            .publish((ADMIN, symbol_short!("accepted")), (old_admin, new_admin));
+
+        Ok(())
+    }
+
+    fn cancel_admin_proposal(e: Env) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
+
+        let pending_admin = match read_pending_admin(&e) {
+            Some(pending_admin) => pending_admin,
+            None => return Err(ContractError::NoPendingAdmin),
+        };
+        clear_pending_admin(&e);
+
+        e.events()
+            .publish((ADMIN, symbol_short!("cancelled")), pending_admin);
+
+        Ok(())
+    }
+
+    fn get_pending_admin(e: Env) -> Option<Address> {
+        read_pending_admin(&e)
+    }
+
+    fn set_guardian(e: Env, guardian: Address) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
+        ensure_not_governed(&e)?;
+
+        write_guardian(&e, &guardian);
+
+        Ok(())
+    }
+
+    fn get_guardian(e: Env) -> Option<Address> {
+        read_guardian(&e)
+    }
+
+    fn pause(e: Env) -> Result<(), ContractError> {
+        let guardian = match read_guardian(&e) {
+            Some(guardian) => guardian,
+            None => return Err(ContractError::GuardianNotFound),
+        };
+        guardian.require_auth();
+
+        write_paused(&e, true);
+
+        e.events()
+            .publish((PAUSE, symbol_short!("paused")), guardian);
+
+        Ok(())
+    }
+
+    fn unpause(e: Env) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
+
+        write_paused(&e, false);
+
+        e.events()
+            .publish((PAUSE, symbol_short!("unpaused")), read_admin(&e)?);
+
+        Ok(())
+    }
+
+    fn get_pause_state(e: Env) -> bool {
+        read_paused(&e)
+    }
+
+    fn announce_upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
+        ensure_not_governed(&e)?;
+
+        schedule_upgrade(&e, new_wasm_hash)
+    }
+
+    fn cancel_upgrade(e: Env) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
+        ensure_not_governed(&e)?;
+
+        discard_pending_upgrade(&e)
+    }
+
+    fn execute_upgrade(e: Env) -> Result<(), ContractError> {
+        let pending_upgrade = match read_pending_upgrade(&e) {
+            Some(pending_upgrade) => pending_upgrade,
+            None => return Err(ContractError::NoPendingUpgrade),
+        };
+
+        if e.ledger().sequence() < pending_upgrade.executable_ledger {
+            return Err(ContractError::UpgradeNotReady);
+        }
+
+        clear_pending_upgrade(&e);
+
+        e.events().publish(
+            (UPGRADE, symbol_short!("executed")),
+            pending_upgrade.wasm_hash.clone(),
+        );
+
+        e.deployer()
+            .update_current_contract_wasm(pending_upgrade.wasm_hash);
+
+        Ok(())
+    }
+
+    fn set_upgrade_delay(e: Env, delay: u32) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
+        ensure_not_governed(&e)?;
+
+        set_upgrade_delay(&e, delay)
+    }
+
+    fn get_pending_upgrade(e: Env) -> Option<PendingUpgrade> {
+        read_pending_upgrade(&e)
+    }
+
+    fn get_upgrade_delay(e: Env) -> u32 {
+        read_upgrade_delay(&e)
+    }
+
+    fn get_pending_upgrade_delay(e: Env) -> Option<PendingUpgradeDelay> {
+        read_pending_upgrade_delay(&e)
+            .filter(|pending_delay| e.ledger().sequence() < pending_delay.effective_ledger)
+    }
+
+    fn setup_council(e: Env, members: Vec<Address>, quorum: u32) -> Result<(), ContractError> {
+        authenticate_admin(&e)?;
+
+        if has_council(&e) {
+            return Err(ContractError::CouncilAlreadySet);
+        }
+
+        validate_council(&members, quorum)?;
+
+        write_council(&e, &members);
+        write_quorum(&e, quorum);
+
+        e.events()
+            .publish((COUNCIL, symbol_short!("setup")), (members, quorum));
+
+        Ok(())
+    }
+
+    fn create_proposal(
+        e: Env,
+        proposer: Address,
+        action: ProposalAction,
+    ) -> Result<u64, ContractError> {
+        authenticate_council_member(&e, &proposer)?;
+
+        let proposal = write_new_proposal(&e, proposer.clone(), action);
+
+        e.events().publish(
+            (COUNCIL, symbol_short!("proposed")),
+            (proposal.id, proposer, proposal.action),
+        );
+
+        Ok(proposal.id)
+    }
+
+    fn approve_proposal(e: Env, member: Address, proposal_id: u64) -> Result<(), ContractError> {
+        authenticate_council_member(&e, &member)?;
+
+        let mut proposal = match read_proposal(&e, proposal_id) {
+            Some(proposal) => proposal,
+            None => return Err(ContractError::ProposalNotFound),
+        };
+
+        if proposal.executed {
+            return Err(ContractError::ProposalAlreadyExecuted);
+        }
+
+        if e.ledger().sequence() > proposal.expiration_ledger {
+            return Err(ContractError::ProposalExpired);
+        }
+
+        if proposal.approvals.contains(&member) {
+            return Err(ContractError::AlreadyApproved);
+        }
+
+        proposal.approvals.push_back(member.clone());
+        write_proposal(&e, &proposal);
+
+        e.events()
+            .publish((COUNCIL, symbol_short!("approved")), (proposal_id, member));
+
+        Ok(())
+    }
+
+    fn execute_proposal(e: Env, proposal_id: u64) -> Result<(), ContractError> {
+        let mut proposal = match read_proposal(&e, proposal_id) {
+            Some(proposal) => proposal,
+            None => return Err(ContractError::ProposalNotFound),
+        };
+
+        if proposal.executed {
+            return Err(ContractError::ProposalAlreadyExecuted);
+        }
+
+        if e.ledger().sequence() > proposal.expiration_ledger {
+            return Err(ContractError::ProposalExpired);
+        }
+
+        if count_approvals(&e, &proposal) < read_quorum(&e) {
+            return Err(ContractError::QuorumNotReached);
+        }
+
+        proposal.executed = true;
+        write_proposal(&e, &proposal);
+
+        match proposal.action {
+            ProposalAction::AnnounceUpgrade(new_wasm_hash) => {
+                schedule_upgrade(&e, new_wasm_hash)?;
+            }
+            ProposalAction::CancelUpgrade => {
+                discard_pending_upgrade(&e)?;
+            }
+            ProposalAction::SetUpgradeDelay(delay) => {
+                set_upgrade_delay(&e, delay)?;
+            }
+            ProposalAction::SetupBridge(bridge_contract) => {
+                write_bridge_contract(&e, &bridge_contract);
+            }
+            ProposalAction::SetGuardian(guardian) => {
+                write_guardian(&e, &guardian);
+            }
+            ProposalAction::SetupNewNode(operator) => {
+                register_node(&e, operator)?;
+            }
+            ProposalAction::RemoveNode(operator) => {
+                deregister_node(&e, operator)?;
+            }
+            ProposalAction::AddNewChain(chain_name, chain_id, oracle_address) => {
+                register_chain(&e, chain_name, chain_id, oracle_address)?;
+            }
+            ProposalAction::RemoveChain(chain_id) => {
+                deregister_chain(&e, chain_id)?;
+            }
+            ProposalAction::PurgeTransfer(tx_id) => {
+                purge_transfer(&e, tx_id)?;
+            }
+            ProposalAction::SetConsensusPolicy(policy) => {
+                set_consensus_policy(&e, policy)?;
+            }
+            ProposalAction::SetConsensusQuorum(quorum) => {
+                set_consensus_quorum(&e, quorum)?;
+            }
+            ProposalAction::SetBondConfig(config) => {
+                set_bond_config(&e, config)?;
+            }
+            ProposalAction::SetSlashingConfig(config) => {
+                set_slashing_config(&e, config)?;
+            }
+            ProposalAction::UnjailOperator(operator) => {
+                unjail(&e, operator)?;
+            }
+            ProposalAction::WithdrawSlashed(to, amount) => {
+                let config = read_bond_config_or_err(&e)?;
+                withdraw_slashed_stake(&e, &config, &to, amount)?;
+            }
+            ProposalAction::SetChainSigners(chain_id, signers) => {
+                set_chain_signers(&e, chain_id, signers)?;
+            }
+            ProposalAction::SetRevealDelay(reveal_delay) => {
+                set_reveal_delay(&e, reveal_delay)?;
+            }
+            ProposalAction::SetPushEnabled(enabled) => {
+                set_push_enabled(&e, enabled);
+            }
+            ProposalAction::SetUpkeepConfig(config) => {
+                set_upkeep_config(&e, config)?;
+            }
+            ProposalAction::SetKeeperRewardConfig(config) => {
+                set_keeper_reward_config(&e, config)?;
+            }
+            // The funder signs the execution, which covers the transfer into the pool.
+            ProposalAction::FundRewardPool(from, amount) => {
+                from.require_auth();
+                fund_rewards(&e, from, amount)?;
+            }
+            ProposalAction::WithdrawRewardPool(to, amount) => {
+                withdraw_rewards(&e, to, amount)?;
+            }
+            ProposalAction::SetQuorum(quorum) => {
+                validate_council(&read_council(&e), quorum)?;
+                write_quorum(&e, quorum);
+            }
+            ProposalAction::AddMember(member) => {
+                if is_council_member(&e, &member) {
+                    return Err(ContractError::DuplicateCouncilMember);
+                }
+                let mut members = read_council(&e);
+                members.push_back(member);
+                write_council(&e, &members);
+            }
+            ProposalAction::RemoveMember(member) => {
+                let mut members = read_council(&e);
+                match members.first_index_of(&member) {
+                    Some(index) => members.remove(index),
+                    None => return Err(ContractError::NotCouncilMember),
+                };
+                validate_council(&members, read_quorum(&e))?;
+                write_council(&e, &members);
+            }
+        }
+
+        e.events()
+            .publish((COUNCIL, symbol_short!("executed")), proposal_id);
+
+        Ok(())
+    }
+
+    fn get_proposal(e: Env, proposal_id: u64) -> Result<Proposal, ContractError> {
+        match read_proposal(&e, proposal_id) {
+            Some(proposal) => Ok(proposal),
+            None => Err(ContractError::ProposalNotFound),
+        }
+    }
+
+    fn get_council(e: Env) -> Vec<Address> {
+        read_council(&e)
+    }
+
+    fn get_council_quorum(e: Env) -> u32 {
+        read_quorum(&e)
+    }
+}
+
+fn register_node(e: &Env, operator: Address) -> Result<Address, ContractError> {
+    if read_is_operator(e, operator.clone()) {
+        return Err(ContractError::AlreadyNodeOperator);
+    }
+    // An absolute threshold must still be a majority once the new operator joins.
+    let policy = read_consensus_policy(e);
+    if let ConsensusPolicy::Absolute(_) = policy {
+        validate_consensus_policy(&policy, read_operator_list(e).len() + 1)?;
+    }
+    // With staking enabled the operator signs the node setup to post its minimum bond.
+    if let Some(config) = read_bond_config(e) {
+        operator.require_auth();
+        deposit_bond(e, &config, &operator, config.min_bond)?;
+    }
+
+    let receptacle_address = create_receptacle(e, &operator);
+
+    let receptacle_contract = oracle_receptacle::Client::new(e, &receptacle_address);
+
+    receptacle_contract.initialize(&e.current_contract_address(), &operator);
+
+    write_receptacle_address(e, operator, receptacle_address.clone());
+
+    Ok(receptacle_address)
+}
+
+fn deregister_node(e: &Env, operator: Address) -> Result<(), ContractError> {
+    if !read_is_operator(e, operator.clone()) {
+        return Err(ContractError::NodeNotFound);
+    }
+
+    // An absolute threshold must stay reachable by the operators that remain.
+    if let ConsensusPolicy::Absolute(count) = read_consensus_policy(e) {
+        if count > read_operator_list(e).len() - 1 {
+            return Err(ContractError::InvalidConsensusPolicy);
+        }
+    }
+    // The default quorum of one stays valid as long as any operator is registered again.
+    let quorum = read_consensus_quorum(e);
+    if quorum > 1 && quorum > read_operator_list(e).len() - 1 {
+        return Err(ContractError::InvalidConsensusPolicy);
+    }
+
+    // A removed operator's stake leaves the active set and unbonds like a voluntary exit.
+    if let Some(config) = read_bond_config(e) {
+        let bond = read_bond(e, &operator);
+        if bond > 0 {
+            start_unbonding(e, &config, &operator, bond)?;
+        }
+    }
+
+    write_jailed(e, &operator, false);
+    delete_operator_key(e, &operator);
+    delete_receptacle(e, operator);
+
+    Ok(())
+}
+
+fn set_consensus_policy(e: &Env, policy: ConsensusPolicy) -> Result<(), ContractError> {
+    validate_consensus_policy(&policy, read_operator_list(e).len())?;
+    if read_bond_config(e).is_some() && matches!(policy, ConsensusPolicy::Absolute(_)) {
+        return Err(ContractError::InvalidConsensusPolicy);
+    }
+
+    write_consensus_policy(e, &policy);
+
+    e.events()
+        .publish((CONSENSUS, symbol_short!("policy")), policy);
+
+    Ok(())
+}
+
+// Setting a delay turns on commit-reveal mode; clearing it goes back to plain commits.
+fn set_reveal_delay(e: &Env, reveal_delay: Option<u32>) -> Result<(), ContractError> {
+    validate_reveal_delay(reveal_delay)?;
+
+    write_reveal_delay(e, reveal_delay);
+
+    e.events()
+        .publish((CONSENSUS, symbol_short!("reveal")), reveal_delay);
+
+    Ok(())
+}
+
+// With pushes off, receptacles keep every transfer queued and only upkeep executes them.
+fn set_push_enabled(e: &Env, enabled: bool) {
+    write_push_enabled(e, enabled);
+
+    e.events()
+        .publish((CONSENSUS, symbol_short!("push")), enabled);
+}
+
+fn set_bond_config(e: &Env, config: BondConfig) -> Result<(), ContractError> {
+    validate_bond_config(e, &config)?;
+    // Weighted votes need a threshold relative to total stake.
+    if matches!(read_consensus_policy(e), ConsensusPolicy::Absolute(_)) {
+        return Err(ContractError::InvalidConsensusPolicy);
+    }
+
+    write_bond_config(e, &config);
+
+    e.events()
+        .publish((STAKING, symbol_short!("config")), config);
+
+    Ok(())
+}
+
+fn set_slashing_config(e: &Env, config: SlashingConfig) -> Result<(), ContractError> {
+    validate_slashing_config(&config)?;
+
+    write_slashing_config(e, &config);
+
+    e.events()
+        .publish((SLASHING, symbol_short!("config")), config);
+
+    Ok(())
+}
+
+fn set_upkeep_config(e: &Env, config: UpkeepConfig) -> Result<(), ContractError> {
+    validate_upkeep_config(&config)?;
+
+    write_upkeep_config(e, &config);
+
+    e.events()
+        .publish((UPKEEP, symbol_short!("config")), config);
+
+    Ok(())
+}
+
+fn set_keeper_reward_config(e: &Env, config: KeeperRewardConfig) -> Result<(), ContractError> {
+    validate_keeper_reward_config(e, &config)?;
+
+    write_keeper_reward_config(e, &config);
+
+    e.events()
+        .publish((UPKEEP, symbol_short!("reward")), config);
+
+    Ok(())
+}
+
+fn fund_rewards(e: &Env, from: Address, amount: i128) -> Result<i128, ContractError> {
+    let config = read_keeper_reward_config_or_err(e)?;
+    let pool = fund_reward_pool(e, &config, &from, amount)?;
+
+    e.events()
+        .publish((UPKEEP, symbol_short!("funded")), amount);
+
+    Ok(pool)
+}
+
+fn withdraw_rewards(e: &Env, to: Address, amount: i128) -> Result<i128, ContractError> {
+    let config = read_keeper_reward_config_or_err(e)?;
+    let pool = withdraw_reward_pool(e, &config, &to, amount)?;
+
+    e.events()
+        .publish((UPKEEP, symbol_short!("withdraw")), (to, amount));
+
+    Ok(pool)
+}
+
+fn reward_keeper(e: &Env, keeper: &Address, executed: u32) -> i128 {
+    let reward = pay_keeper_reward(e, keeper, executed);
+    if reward > 0 {
+        e.events()
+            .publish((UPKEEP, symbol_short!("paid")), (keeper.clone(), reward));
+    }
+    reward
+}
+
+// Faults are always counted; slashing and jailing only apply once a slashing config is set.
+fn record_fault(e: &Env, operator: &Address, tx_id: &Bytes) -> Result<(), ContractError> {
+    let faults = read_faults(e, operator) + 1;
+    write_faults(e, operator, faults);
+
+    e.events().publish(
+        (SLASHING, symbol_short!("fault")),
+        (operator.clone(), tx_id.clone(), faults),
+    );
+
+    let Some(config) = read_slashing_config(e) else {
+        return Ok(());
+    };
+
+    if read_bond_config(e).is_some() {
+        let slashed = slash_bond(e, operator, config.slash_bps);
+        if slashed > 0 {
+            e.events().publish(
+                (SLASHING, symbol_short!("slash")),
+                (operator.clone(), slashed),
+            );
+        }
+    }
+
+    if faults >= config.max_faults {
+        jail(e, operator)?;
+    }
+
+    Ok(())
+}
+
+// A jailed operator's remaining stake starts unbonding so it no longer counts towards consensus.
+fn jail(e: &Env, operator: &Address) -> Result<(), ContractError> {
+    write_jailed(e, operator, true);
+
+    if let Some(config) = read_bond_config(e) {
+        let bond = read_bond(e, operator);
+        if bond > 0 {
+            start_unbonding(e, &config, operator, bond)?;
+        }
+    }
+
+    e.events()
+        .publish((SLASHING, symbol_short!("jail")), operator.clone());
+
+    Ok(())
+}
+
+fn unjail(e: &Env, operator: Address) -> Result<(), ContractError> {
+    if !is_jailed(e, &operator) {
+        return Err(ContractError::OperatorNotJailed);
+    }
+
+    write_jailed(e, &operator, false);
+    write_faults(e, &operator, 0);
+
+    e.events()
+        .publish((SLASHING, symbol_short!("unjail")), operator);
+
+    Ok(())
+}
+
+fn set_consensus_quorum(e: &Env, quorum: u32) -> Result<(), ContractError> {
+    validate_consensus_quorum(quorum, read_operator_list(e).len())?;
+
+    write_consensus_quorum(e, quorum);
+
+    e.events()
+        .publish((CONSENSUS, symbol_short!("quorum")), quorum);
+
+    Ok(())
+}
+
+fn register_chain(
+    e: &Env,
+    chain_name: String,
+    chain_id: u32,
+    oracle_address: String,
+) -> Result<(), ContractError> {
+    if chain_is_supported(e, chain_id) {
+        return Err(ContractError::ChainAlreadyAdded);
+    }
+
+    write_chain(e, chain_name, chain_id, &oracle_address);
+
+    Ok(())
+}
+
+fn deregister_chain(e: &Env, chain_id: u32) -> Result<(), ContractError> {
+    if !chain_is_supported(e, chain_id) {
+        return Err(ContractError::ChainNotFound);
+    }
+
+    delete_chain(e, chain_id);
+
+    Ok(())
+}
+
+// An empty signer list turns source signature verification off for the chain.
+fn set_chain_signers(
+    e: &Env,
+    chain_id: u32,
+    signers: Vec<BytesN<20>>,
+) -> Result<(), ContractError> {
+    read_chain(e, chain_id)?;
+
+    write_chain_signers(e, chain_id, &signers);
+
+    e.events()
+        .publish((CHAIN, symbol_short!("signers")), (chain_id, signers));
+
+    Ok(())
+}
+
+fn schedule_upgrade(e: &Env, new_wasm_hash: BytesN<32>) -> Result<(), ContractError> {
+    if read_pending_upgrade(e).is_some() {
+        return Err(ContractError::UpgradeAlreadyPending);
+    }
+
+    let pending_upgrade = write_pending_upgrade(e, new_wasm_hash);
+
+    e.events().publish(
+        (UPGRADE, symbol_short!("announced")),
+        (pending_upgrade.wasm_hash, pending_upgrade.executable_ledger),
+    );
+
+    Ok(())
+}
+
+// Raising the delay applies at once. Lowering it only applies once the current delay has passed,
+// so an upgrade announced right after the change still waits the full original delay.
+fn set_upgrade_delay(e: &Env, delay: u32) -> Result<(), ContractError> {
+    validate_upgrade_delay(delay)?;
+
+    let effective_ledger = if delay >= read_upgrade_delay(e) {
+        write_upgrade_delay(e, delay);
+        e.ledger().sequence()
+    } else {
+        write_pending_upgrade_delay(e, delay).effective_ledger
+    };
+
+    e.events()
+        .publish((UPGRADE, symbol_short!("delay")), (delay, effective_ledger));
+
+    Ok(())
+}
+
+fn discard_pending_upgrade(e: &Env) -> Result<(), ContractError> {
+    let pending_upgrade = match read_pending_upgrade(e) {
+        Some(pending_upgrade) => pending_upgrade,
+        None => return Err(ContractError::NoPendingUpgrade),
+    };
+
+    clear_pending_upgrade(e);
+
+    e.events().publish(
+        (UPGRADE, symbol_short!("cancelled")),
+        pending_upgrade.wasm_hash,
+    );
+
+    Ok(())
+}
+
+fn read_vote_weights(e: &Env, receptacles: &Vec<ReceptacleDetails>) -> Vec<i128> {
+    let mut weights: Vec<i128> = Vec::new(e);
+    for receptacle in receptacles.iter() {
+        weights.push_back(read_vote_weight(e, &receptacle.operator));
+    }
+    weights
+}
+
+// Reads the attestation of every receptacle whose node carries weight. Returns the votes, the
+// receptacle index behind each vote and the indexes of nodes without a countable attestation.
+fn collect_votes(
+    e: &Env,
+    receptacles: &Vec<ReceptacleDetails>,
+    weights: &Vec<i128>,
+    reveal_delay: Option<u32>,
+    tx_id: &Bytes,
+    reads: &mut u32,
+) -> (Vec<(Bytes, i128)>, Vec<u32>, Vec<u32>) {
+    let mut votes: Vec<(Bytes, i128)> = Vec::new(e);
+    let mut voters: Vec<u32> = Vec::new(e);
+    let mut absentees: Vec<u32> = Vec::new(e);
+    let mut commit_ledgers: Vec<u32> = Vec::new(e);
+
+    for j in 0..receptacles.len() {
+        let weight = weights.get_unchecked(j);
+        if weight == 0 {
+            continue;
+        }
+        let receptacle_addr = receptacles.get_unchecked(j).receptacle_address;
+        let receptacle_contract = oracle_receptacle::Client::new(e, &receptacle_addr);
+        let tx_data_val = receptacle_contract.get_transfer(tx_id);
+        *reads += 1;
+        let tx_data_array: Vec<Bytes> = Vec::from_val(e, &tx_data_val);
+        if !is_valid_attestation(tx_id, &tx_data_array) {
+            absentees.push_back(j);
+            continue;
+        }
+        if reveal_delay.is_some() {
+            // Only attestations revealed against an earlier commitment count, so a plain commit
+            // copied from someone else's reveal is ignored.
+            *reads += 1;
+            let Some(commitment) = receptacle_contract
+                .get_commitment(tx_id)
+                .filter(|commitment| commitment.revealed)
+            else {
+                absentees.push_back(j);
+                continue;
+            };
+            commit_ledgers.push_back(commitment.ledger);
+        }
+        let data_bytes = tx_data_array.clone().to_xdr(e);
+        votes.push_back((data_bytes, weight));
+        voters.push_back(j);
+    }
+
+    // Commitments made once the reveal window opened could copy an earlier reveal.
+    if let Some(reveal_delay) = reveal_delay {
+        let mut window_open = u32::MAX;
+        for ledger in commit_ledgers.iter() {
+            window_open = u32::min(window_open, ledger.saturating_add(reveal_delay));
+        }
+
+        let mut timely_votes: Vec<(Bytes, i128)> = Vec::new(e);
+        let mut timely_voters: Vec<u32> = Vec::new(e);
+        for k in 0..votes.len() {
+            if commit_ledgers.get_unchecked(k) < window_open {
+                timely_votes.push_back(votes.get_unchecked(k));
+                timely_voters.push_back(voters.get_unchecked(k));
+            } else {
+                absentees.push_back(voters.get_unchecked(k));
+            }
+        }
+        votes = timely_votes;
+        voters = timely_voters;
+    }
+
+    (votes, voters, absentees)
+}
+
+// Runs consensus for one transfer and executes it once the threshold is met. Weights of nodes
+// jailed along the way are zeroed so later transfers in the same upkeep no longer count them.
+fn process_transfer(
+    e: &Env,
+    receptacles: &Vec<ReceptacleDetails>,
+    weights: &mut Vec<i128>,
+    reveal_delay: Option<u32>,
+    tx_id: &Bytes,
+    reads: &mut u32,
+) -> Result<UpkeepOutcome, ContractError> {
+    // Already executed through signed or pushed attestations, so only the receptacles need clearing.
+    if is_transfer_processed(e, tx_id) {
+        remove_from_receptacles(e, receptacles, tx_id, reads);
+        return Ok(UpkeepOutcome::AlreadyProcessed);
+    }
+
+    let (votes, voters, absentees) =
+        collect_votes(e, receptacles, weights, reveal_delay, tx_id, reads);
+    if votes.is_empty() {
+        return Ok(UpkeepOutcome::NotFound);
+    }
+
+    let Some(validated_data_bytes) = threshold_validation(e, votes.clone()) else {
+        return Ok(UpkeepOutcome::NoConsensus);
+    };
+    // A transfer that cannot execute is reported on its own so the rest of the batch still runs.
+    let Some((origin_chain, decoded)) = Vec::<Bytes>::from_xdr(e, &validated_data_bytes)
+        .ok()
+        .and_then(|validated| decode_attestation(e, &validated).ok())
+    else {
+        fail_transfer(e, tx_id);
+        return Ok(UpkeepOutcome::Failed);
+    };
+
+    // Transfers on a paused route stay queued in the receptacles until it resumes.
+    if ensure_route_active(e, decoded.token.clone(), origin_chain).is_err() {
+        return Ok(UpkeepOutcome::RouteInactive);
+    }
+
+    if !try_settle_transfer(e, tx_id, &decoded)? {
+        fail_transfer(e, tx_id);
+        return Ok(UpkeepOutcome::Failed);
+    }
+    remove_from_receptacles(e, receptacles, tx_id, reads);
+
+    // Nodes that attested to anything else are at fault once the transfer executes.
+    for k in 0..votes.len() {
+        let j = voters.get_unchecked(k);
+        let operator = receptacles.get_unchecked(j).operator;
+        let agreed = votes.get_unchecked(k).0 == validated_data_bytes;
+        record_attestation(e, &operator, agreed);
+        if agreed {
+            continue;
+        }
+        record_fault(e, &operator, tx_id)?;
+
+        let weight = if is_jailed(e, &operator) {
+            0
+        } else {
+            read_vote_weight(e, &operator)
+        };
+        weights.set(j, weight);
+    }
+    let mut missed: Vec<Address> = Vec::new(e);
+    for j in absentees.iter() {
+        let operator = receptacles.get_unchecked(j).operator;
+        record_missed(e, &operator);
+        missed.push_back(operator);
+    }
+    let hash: BytesN<32> = e.crypto().sha256(&validated_data_bytes).into();
+    write_missed_votes(e, tx_id, &hash, &missed);
+
+    Ok(UpkeepOutcome::Executed)
+}
+
+// An operator recorded as missing a transfer that votes after it executed is credited for the
+// vote instead, and faulted if the vote conflicts.
+fn credit_late_vote(
+    e: &Env,
+    tx_id: &Bytes,
+    operator: &Address,
+    hash: &BytesN<32>,
+) -> Result<(), ContractError> {
+    let Some((winner, mut missed)) = read_missed_votes(e, tx_id) else {
+        return Ok(());
+    };
+    let Some(index) = missed.first_index_of(operator) else {
+        return Ok(());
+    };
+    missed.remove(index);
+    write_missed_votes(e, tx_id, &winner, &missed);
+
+    let agreed = *hash == winner;
+    record_late_attestation(e, operator, agreed);
+    if !agreed {
+        record_fault(e, operator, tx_id)?;
+    }
+    Ok(())
+}
+
+// Transfers held by any receptacle, each once and oldest first by the earliest ledger it was
+// committed at, so upkeep does not depend on any single node having seen everything.
+// Each receptacle lists its oldest pending ids only. The listing call and every id it returns
+// count as reads.
+fn read_pending_transfer_ids(
+    e: &Env,
+    receptacles: &Vec<ReceptacleDetails>,
+    reads: &mut u32,
+) -> Vec<Bytes> {
+    let mut earliest: Map<Bytes, u32> = Map::new(e);
+    for receptacle in receptacles.iter() {
+        let pending = oracle_receptacle::Client::new(e, &receptacle.receptacle_address)
+            .get_pending_transfers(&0, &MAX_LISTED_TRANSFERS);
+        *reads += 1 + pending.len();
+        for (tx_id, ledger) in pending.iter() {
+            let ledger = earliest
+                .get(tx_id.clone())
+                .map_or(ledger, |current| u32::min(current, ledger));
+            earliest.set(tx_id, ledger);
+        }
+    }
+
+    let mut ordered: Map<(u32, Bytes), ()> = Map::new(e);
+    for (tx_id, ledger) in earliest.iter() {
+        ordered.set((ledger, tx_id), ());
+    }
+
+    let mut tx_ids: Vec<Bytes> = Vec::new(e);
+    for (_, tx_id) in ordered.keys().iter() {
+        tx_ids.push_back(tx_id);
+    }
+    tx_ids
+}
+
+fn remove_from_receptacles(
+    e: &Env,
+    receptacles: &Vec<ReceptacleDetails>,
+    tx_id: &Bytes,
+    reads: &mut u32,
+) {
+    for receptacle in receptacles.iter() {
+        oracle_receptacle::Client::new(e, &receptacle.receptacle_address).remove_transfer(tx_id);
+    }
+    *reads += receptacles.len();
+}
+
+// Drops an id that will never execute, such as a malformed or never-confirmed transfer, from every
+// receptacle, jailed ones included, along with any pushed votes for it.
+fn purge_transfer(e: &Env, tx_id: Bytes) -> Result<(), ContractError> {
+    let mut reads: u32 = 0;
+    remove_from_receptacles(e, &read_receptacle_list(e), &tx_id, &mut reads);
+    clear_pending_votes(e, &tx_id);
+    clear_transfer_failure(e, &tx_id);
+
+    e.events()
+        .publish((TRANSFER, symbol_short!("purged")), tx_id);
+
+    Ok(())
+}
+
+fn decode_attestation(
+    e: &Env,
+    attestation: &Vec<Bytes>,
+) -> Result<(u32, DecodedTransferData), ContractError> {
+    if attestation.len() <= 5 {
+        return Err(ContractError::MalformedTransferData);
+    }
+
+    let origin_chain = u32::from_xdr(e, &attestation.get_unchecked(1))
+        .map_err(|_| ContractError::MalformedTransferData)?;
+    let recipient = Address::from_xdr(e, &attestation.get_unchecked(3))
+        .map_err(|_| ContractError::MalformedTransferData)?;
+    let token = Address::from_xdr(e, &attestation.get_unchecked(4))
+        .map_err(|_| ContractError::MalformedTransferData)?;
+    let amount = i128::from_xdr(e, &attestation.get_unchecked(5))
+        .map_err(|_| ContractError::MalformedTransferData)?;
+
+    Ok((
+        origin_chain,
+        DecodedTransferData {
+            recipient,
+            token,
+            amount,
+        },
+    ))
+}
+
+fn settle_transfer(
+    e: &Env,
+    tx_id: &Bytes,
+    decoded: &DecodedTransferData,
+) -> Result<(), ContractError> {
+    let bridge_client = bridge_liquidity::Client::new(e, &read_bridge_contract(e)?);
+    bridge_client.execute_evm_to_soroban_transfer(
+        &decoded.recipient,
+        &decoded.token,
+        &decoded.amount,
+    );
+    write_transfer_processed(e, tx_id);
+    clear_pending_votes(e, tx_id);
+    clear_transfer_failure(e, tx_id);
+
+    Ok(())
+}
+
+// The transfer stays queued; the failure is recorded so `check_upkeep` stops offering it.
+fn fail_transfer(e: &Env, tx_id: &Bytes) {
+    write_transfer_failure(e, tx_id);
+
+    e.events()
+        .publish((TRANSFER, symbol_short!("failed")), tx_id.clone());
+}
+
+// Like `settle_transfer`, but a failed bridge call leaves the transfer pending instead of trapping.
+fn try_settle_transfer(
+    e: &Env,
+    tx_id: &Bytes,
+    decoded: &DecodedTransferData,
+) -> Result<bool, ContractError> {
+    let bridge_client = bridge_liquidity::Client::new(e, &read_bridge_contract(e)?);
+    let executed = bridge_client.try_execute_evm_to_soroban_transfer(
+        &decoded.recipient,
+        &decoded.token,
+        &decoded.amount,
+    );
+    if !matches!(executed, Ok(Ok(()))) {
+        return Ok(false);
+    }
+    write_transfer_processed(e, tx_id);
+    clear_pending_votes(e, tx_id);
+    clear_transfer_failure(e, tx_id);
+
+    Ok(true)
+}
+
+// Builds the same attestation a receptacle reports from `get_transfer` for this payload.
+fn build_attestation(
+    e: &Env,
+    tx_id: Bytes,
+    origin_chain: u32,
+    sender: String,
+    transfer_data: Bytes,
+) -> Result<(DecodedTransferData, Bytes), ContractError> {
+    let decoded = decode_transfer_data(&transfer_data)?;
+
+    let attestation: Vec<Bytes> = Vec::from_array(
+        e,
+        [
+            tx_id,
+            origin_chain.to_xdr(e),
+            sender.to_xdr(e),
+            decoded.recipient.clone().to_xdr(e),
+            decoded.token.clone().to_xdr(e),
+            decoded.amount.to_xdr(e),
+        ],
+    );
+
+    Ok((decoded, attestation.to_xdr(e)))
 }
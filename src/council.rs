@@ -0,0 +1,165 @@
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, String, Vec};
+
+use crate::{
+    consensus::ConsensusPolicy,
+    data::{DataKey, BUMP_AMOUNT, LIFETIME_THRESHOLD, PROPOSAL_LIFETIME},
+    error::ContractError,
+    faults::SlashingConfig,
+    rewards::KeeperRewardConfig,
+    staking::BondConfig,
+    upkeep::UpkeepConfig,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ProposalAction {
+    AnnounceUpgrade(BytesN<32>),
+    CancelUpgrade,
+    SetUpgradeDelay(u32),
+    SetupBridge(Address),
+    SetGuardian(Address),
+    SetupNewNode(Address),
+    RemoveNode(Address),
+    AddNewChain(String, u32, String), //chain name, chain id, oracle address
+    RemoveChain(u32),
+    SetConsensusPolicy(ConsensusPolicy),
+    SetConsensusQuorum(u32),
+    SetBondConfig(BondConfig),
+    SetSlashingConfig(SlashingConfig),
+    UnjailOperator(Address),
+    WithdrawSlashed(Address, i128), //recipient, amount
+    SetChainSigners(u32, Vec<BytesN<20>>),
+    SetRevealDelay(Option<u32>),
+    SetPushEnabled(bool),
+    SetUpkeepConfig(UpkeepConfig),
+    PurgeTransfer(Bytes),
+    SetKeeperRewardConfig(KeeperRewardConfig),
+    FundRewardPool(Address, i128),     //funder, amount
+    WithdrawRewardPool(Address, i128), //recipient, amount
+    SetQuorum(u32),
+    AddMember(Address),
+    RemoveMember(Address),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: Address,
+    pub action: ProposalAction,
+    pub approvals: Vec<Address>,
+    pub expiration_ledger: u32,
+    pub executed: bool,
+}
+
+pub fn has_council(e: &Env) -> bool {
+    let key = DataKey::CouncilMembers;
+    e.storage().instance().has(&key)
+}
+
+pub fn read_council(e: &Env) -> Vec<Address> {
+    let key = DataKey::CouncilMembers;
+    let default_list: Vec<Address> = Vec::new(e);
+
+    e.storage()
+        .instance()
+        .get::<DataKey, Vec<Address>>(&key)
+        .unwrap_or(default_list)
+}
+
+pub fn write_council(e: &Env, members: &Vec<Address>) {
+    let key = DataKey::CouncilMembers;
+    e.storage().instance().set(&key, members);
+}
+
+pub fn is_council_member(e: &Env, member: &Address) -> bool {
+    read_council(e).contains(member)
+}
+
+pub fn read_quorum(e: &Env) -> u32 {
+    let key = DataKey::CouncilQuorum;
+    e.storage().instance().get(&key).unwrap_or(0)
+}
+
+pub fn write_quorum(e: &Env, quorum: u32) {
+    let key = DataKey::CouncilQuorum;
+    e.storage().instance().set(&key, &quorum);
+}
+
+pub fn validate_council(members: &Vec<Address>, quorum: u32) -> Result<(), ContractError> {
+    for i in 0..members.len() {
+        if members.first_index_of(members.get_unchecked(i)) != Some(i) {
+            return Err(ContractError::DuplicateCouncilMember);
+        }
+    }
+
+    if quorum == 0 || quorum > members.len() {
+        return Err(ContractError::InvalidQuorum);
+    }
+
+    Ok(())
+}
+
+pub fn authenticate_council_member(e: &Env, member: &Address) -> Result<(), ContractError> {
+    if !is_council_member(e, member) {
+        return Err(ContractError::NotCouncilMember);
+    }
+    member.require_auth();
+    Ok(())
+}
+
+// Once a council is configured, the privileged actions it covers can only run through a proposal.
+pub fn ensure_not_governed(e: &Env) -> Result<(), ContractError> {
+    if has_council(e) {
+        return Err(ContractError::GovernedByCouncil);
+    }
+    Ok(())
+}
+
+pub fn read_proposal(e: &Env, proposal_id: u64) -> Option<Proposal> {
+    let key = DataKey::Proposal(proposal_id);
+    e.storage().persistent().get(&key)
+}
+
+pub fn write_proposal(e: &Env, proposal: &Proposal) {
+    let key = DataKey::Proposal(proposal.id);
+    e.storage().persistent().set(&key, proposal);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+pub fn write_new_proposal(e: &Env, proposer: Address, action: ProposalAction) -> Proposal {
+    let key = DataKey::ProposalCount;
+    let proposal_id: u64 = e.storage().instance().get(&key).unwrap_or(0);
+    e.storage().instance().set(&key, &(proposal_id + 1));
+
+    let mut approvals: Vec<Address> = Vec::new(e);
+    approvals.push_back(proposer.clone());
+
+    let proposal = Proposal {
+        id: proposal_id,
+        proposer,
+        action,
+        approvals,
+        expiration_ledger: e.ledger().sequence() + PROPOSAL_LIFETIME,
+        executed: false,
+    };
+    write_proposal(e, &proposal);
+
+    proposal
+}
+
+// Approvals from addresses that have since left the council no longer count.
+pub fn count_approvals(e: &Env, proposal: &Proposal) -> u32 {
+    let members = read_council(e);
+    let mut count = 0;
+
+    for i in 0..proposal.approvals.len() {
+        if members.contains(proposal.approvals.get_unchecked(i)) {
+            count += 1;
+        }
+    }
+
+    count
+}
@@ -1,21 +1,66 @@
-use soroban_sdk::{contracttype, Address, Bytes};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN};
+
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+pub(crate) const BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const LIFETIME_THRESHOLD: u32 = BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub(crate) const PROPOSAL_LIFETIME: u32 = 7 * DAY_IN_LEDGERS;
+pub(crate) const DEFAULT_UPGRADE_DELAY: u32 = 2 * DAY_IN_LEDGERS;
+pub(crate) const MIN_UPGRADE_DELAY: u32 = DAY_IN_LEDGERS;
+pub(crate) const MAX_REVEAL_DELAY: u32 = DAY_IN_LEDGERS;
 
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
     Admin,
-    Managers,
+    PendingAdmin, //admin proposed by the current admin, pending acceptance
+    Managers,     //list of addresses allowed to maintain chains and token maps
     BridgeContract,
+    Guardian, //address allowed to pause the oracle during an incident
+    Paused,
     ReceptacleId(Address),
+    ReceptacleOperator(Address), //reverse lookup of ReceptacleId
     OperatorList,
     ReceptaclesList,
-    ConsensusThreshold,
-    ConsensusCount(Bytes),
+    ConsensusPolicy,
+    ConsensusQuorum,
+    RevealDelay,  //ledgers between a commitment and its reveal in commit-reveal mode
+    PushDisabled, //set while receptacle pushes are only queued for upkeep
+    BondConfig,
+    TotalStake,         //sum of all active bonds
+    Bond(Address),      //active bond of an operator
+    Unbonding(Address), //stake of an operator waiting out the unbonding period
+    SlashedStake,
+    SlashingConfig,
+    Faults(Address),
+    Jailed(Address),
+    JailedCount,
+    OperatorStats(Address),
+    OperatorKey(Address),     //ed25519 key an operator signs attestations with
+    KeyOperator(BytesN<32>),  //reverse lookup of OperatorKey
+    ProcessedTransfer(Bytes), //inbound transfers already executed, by tx id
+    PendingVotes(Bytes),
+    MissedVotes(Bytes), //winning hash of an executed transfer and the operators that missed it
+    TransferFailure(Bytes), //ledger at which an inbound transfer last failed to execute
+    UpkeepConfig,
+    KeeperRewardConfig,
+    RewardPool, //keeper rewards funded by the admin and not yet paid out
+
+    CouncilMembers,
+    CouncilQuorum,
+    ProposalCount,
+    Proposal(u64),
+
+    UpgradeDelay,
+    PendingUpgradeDelay, //shorter delay waiting out the current one
+    PendingUpgrade,
 
     ChainOracle(u32),
     IsSuportedChain(u32),
     ChainIdList,
+    ChainPaused(u32),
+    ChainSigners(u32), //EVM addresses allowed to sign inbound payloads from a chain
 
     TokenSupportedChains(Address), //array of all chain ids a token supports
     DestinationChainToken(Address, u32), //destination token address
+    TokenChainPaused(Address, u32),
 }
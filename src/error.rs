@@ -1,3 +1 @@
-soroban_sdk::contractimport!(
-    file = "../global_error/target/wasm32-unknown-unknown/release/liquidsfi_errors.wasm"
-);
+pub use liquidsfi_types::error::ContractError;
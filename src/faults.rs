@@ -0,0 +1,77 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{
+    consensus::MAX_BPS,
+    data::{DataKey, BUMP_AMOUNT, LIFETIME_THRESHOLD},
+    error::ContractError,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SlashingConfig {
+    pub slash_bps: u32,  //portion of the bond slashed per fault
+    pub max_faults: u32, //faults after which the operator is jailed
+}
+
+pub fn read_slashing_config(e: &Env) -> Option<SlashingConfig> {
+    let key = DataKey::SlashingConfig;
+    e.storage().instance().get(&key)
+}
+
+pub fn write_slashing_config(e: &Env, config: &SlashingConfig) {
+    let key = DataKey::SlashingConfig;
+    e.storage().instance().set(&key, config);
+}
+
+pub fn validate_slashing_config(config: &SlashingConfig) -> Result<(), ContractError> {
+    if config.slash_bps > MAX_BPS || config.max_faults == 0 {
+        return Err(ContractError::InvalidSlashingConfig);
+    }
+    Ok(())
+}
+
+pub fn read_faults(e: &Env, operator: &Address) -> u32 {
+    let key = DataKey::Faults(operator.clone());
+    e.storage().persistent().get(&key).unwrap_or(0)
+}
+
+pub fn write_faults(e: &Env, operator: &Address, faults: u32) {
+    let key = DataKey::Faults(operator.clone());
+    if faults == 0 {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, &faults);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+    }
+}
+
+pub fn is_jailed(e: &Env, operator: &Address) -> bool {
+    let key = DataKey::Jailed(operator.clone());
+    e.storage().instance().has(&key)
+}
+
+pub fn read_jailed_count(e: &Env) -> u32 {
+    let key = DataKey::JailedCount;
+    e.storage().instance().get(&key).unwrap_or(0)
+}
+
+pub fn write_jailed(e: &Env, operator: &Address, jailed: bool) {
+    if is_jailed(e, operator) == jailed {
+        return;
+    }
+    let key = DataKey::Jailed(operator.clone());
+    let count = read_jailed_count(e);
+    if jailed {
+        e.storage().instance().set(&key, &true);
+        e.storage()
+            .instance()
+            .set(&DataKey::JailedCount, &(count + 1));
+    } else {
+        e.storage().instance().remove(&key);
+        e.storage()
+            .instance()
+            .set(&DataKey::JailedCount, &(count - 1));
+    }
+}
@@ -4,10 +4,24 @@ mod access;
 mod bridge_liquidity;
 mod chains;
 mod consensus;
-mod contract;
+pub mod contract;
+mod council;
 mod data;
+mod decode;
 mod error;
+mod faults;
+mod mock_bridge;
 mod oracle_receptacle;
+mod pause;
 mod receptacle_ids;
+mod rewards;
+mod signers;
+mod staking;
+mod stats;
 mod tokens;
+mod transfers;
 mod types;
+mod upgrade;
+mod upkeep;
+
+mod test;
@@ -0,0 +1,107 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short, Address,
+    Env, Symbol, Vec,
+};
+
+// Test double for the bridge liquidity contract. It implements the interface the oracle calls,
+// records every call it receives and can be told to fail any of them, or every call for an account.
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MockBridgeError {
+    Failure = 900,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BridgeCall {
+    pub function: Symbol,
+    pub account: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[derive(Clone)]
+#[contracttype]
+enum MockDataKey {
+    Calls,
+    Failing(Symbol),
+    FailingAccount(Address),
+}
+
+pub const TRANSFER_SOROBAN_TO_EVM: Symbol = symbol_short!("to_evm");
+pub const EXECUTE_REBALANCING: Symbol = symbol_short!("rebalance");
+pub const EXECUTE_EVM_TO_SOROBAN: Symbol = symbol_short!("to_sor");
+
+fn record_call(e: &Env, function: Symbol, account: Address, token: Address, amount: i128) {
+    let failing: bool = e
+        .storage()
+        .instance()
+        .get(&MockDataKey::Failing(function.clone()))
+        .unwrap_or(false)
+        || e.storage()
+            .instance()
+            .get(&MockDataKey::FailingAccount(account.clone()))
+            .unwrap_or(false);
+    if failing {
+        panic_with_error!(e, MockBridgeError::Failure);
+    }
+
+    let mut calls: Vec<BridgeCall> = e
+        .storage()
+        .instance()
+        .get(&MockDataKey::Calls)
+        .unwrap_or(Vec::new(e));
+    calls.push_back(BridgeCall {
+        function,
+        account,
+        token,
+        amount,
+    });
+    e.storage().instance().set(&MockDataKey::Calls, &calls);
+}
+
+#[contract]
+pub struct MockBridge;
+
+#[contractimpl]
+impl MockBridge {
+    pub fn transfer_soroban_to_evm(e: Env, user: Address, token: Address, amount: i128) {
+        record_call(&e, TRANSFER_SOROBAN_TO_EVM, user, token, amount);
+    }
+
+    pub fn execute_rebalancing(e: Env, user: Address, token: Address, amount: i128) {
+        record_call(&e, EXECUTE_REBALANCING, user, token, amount);
+    }
+
+    pub fn execute_evm_to_soroban_transfer(
+        e: Env,
+        recipient: Address,
+        token: Address,
+        amount: i128,
+    ) {
+        record_call(&e, EXECUTE_EVM_TO_SOROBAN, recipient, token, amount);
+    }
+
+    pub fn set_failing(e: Env, function: Symbol, failing: bool) {
+        e.storage()
+            .instance()
+            .set(&MockDataKey::Failing(function), &failing);
+    }
+
+    pub fn set_failing_account(e: Env, account: Address, failing: bool) {
+        e.storage()
+            .instance()
+            .set(&MockDataKey::FailingAccount(account), &failing);
+    }
+
+    pub fn get_calls(e: Env) -> Vec<BridgeCall> {
+        e.storage()
+            .instance()
+            .get(&MockDataKey::Calls)
+            .unwrap_or(Vec::new(&e))
+    }
+}
@@ -0,0 +1,59 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{data::DataKey, error::ContractError};
+
+pub fn read_paused(e: &Env) -> bool {
+    let key = DataKey::Paused;
+    e.storage().instance().get(&key).unwrap_or(false)
+}
+
+pub fn write_paused(e: &Env, paused: bool) {
+    let key = DataKey::Paused;
+    e.storage().instance().set(&key, &paused);
+}
+
+pub fn ensure_not_paused(e: &Env) -> Result<(), ContractError> {
+    if read_paused(e) {
+        return Err(ContractError::Paused);
+    }
+    Ok(())
+}
+
+pub fn read_chain_paused(e: &Env, chain_id: u32) -> bool {
+    let key = DataKey::ChainPaused(chain_id);
+    e.storage().instance().get(&key).unwrap_or(false)
+}
+
+pub fn write_chain_paused(e: &Env, chain_id: u32, paused: bool) {
+    let key = DataKey::ChainPaused(chain_id);
+    if paused {
+        e.storage().instance().set(&key, &paused);
+    } else {
+        e.storage().instance().remove(&key);
+    }
+}
+
+pub fn read_token_chain_paused(e: &Env, token_id: Address, chain_id: u32) -> bool {
+    let key = DataKey::TokenChainPaused(token_id, chain_id);
+    e.storage().instance().get(&key).unwrap_or(false)
+}
+
+pub fn write_token_chain_paused(e: &Env, token_id: Address, chain_id: u32, paused: bool) {
+    let key = DataKey::TokenChainPaused(token_id, chain_id);
+    if paused {
+        e.storage().instance().set(&key, &paused);
+    } else {
+        e.storage().instance().remove(&key);
+    }
+}
+
+// A route is open only when both its chain and the token-to-chain map are active.
+pub fn ensure_route_active(e: &Env, token_id: Address, chain_id: u32) -> Result<(), ContractError> {
+    if read_chain_paused(e, chain_id) {
+        return Err(ContractError::ChainPaused);
+    }
+    if read_token_chain_paused(e, token_id, chain_id) {
+        return Err(ContractError::TokenChainPaused);
+    }
+    Ok(())
+}
@@ -1,10 +1,6 @@
 use soroban_sdk::{Address, Env, Vec};
 
-use crate::{
-    consensus::{read_consensus_threshold, write_consensus_threshold},
-    data::DataKey,
-    types::ReceptacleDetails,
-};
+use crate::{data::DataKey, faults::is_jailed, types::ReceptacleDetails};
 
 pub fn read_is_operator(e: &Env, operator: Address) -> bool {
     let key = DataKey::ReceptacleId(operator);
@@ -13,32 +9,39 @@ pub fn read_is_operator(e: &Env, operator: Address) -> bool {
 
 pub fn read_receptacle_address(e: &Env, operator: Address) -> Option<Address> {
     let key = DataKey::ReceptacleId(operator);
-    if let Some(receptacle_addr) = e.storage().instance().get(&key).unwrap() {
-        receptacle_addr
-    } else {
-        None
-    }
+    e.storage().instance().get(&key)
+}
+
+pub fn read_receptacle_operator(e: &Env, receptacle: &Address) -> Option<Address> {
+    let key = DataKey::ReceptacleOperator(receptacle.clone());
+    e.storage().instance().get(&key)
 }
 
 pub fn write_receptacle_address(e: &Env, operator: Address, receptacle_addr: Address) {
     let key = DataKey::ReceptacleId(operator.clone());
     e.storage().instance().set(&key, &receptacle_addr);
 
+    let key_receptacle = DataKey::ReceptacleOperator(receptacle_addr);
+    e.storage().instance().set(&key_receptacle, &operator);
+
     let key_opr = DataKey::OperatorList;
     let mut list: Vec<Address> = read_operator_list(e);
     list.push_back(operator);
 
     e.storage().instance().set(&key_opr, &list);
-    let consensus_val = read_consensus_threshold(e) + 1;
-    write_consensus_threshold(e, consensus_val);
 }
 
 pub fn delete_receptacle(e: &Env, operator: Address) {
+    if let Some(receptacle_addr) = read_receptacle_address(e, operator.clone()) {
+        let key_receptacle = DataKey::ReceptacleOperator(receptacle_addr);
+        e.storage().instance().remove(&key_receptacle);
+    }
+
     let key = DataKey::ReceptacleId(operator.clone());
     e.storage().instance().remove(&key);
 
     let opr_list: Vec<Address> = read_operator_list(e);
-    let mut updated_list: Vec<Address> = Vec::new(&e);
+    let mut updated_list: Vec<Address> = Vec::new(e);
 
     for i in 0..opr_list.len() {
         if opr_list.get_unchecked(i) != operator {
@@ -49,35 +52,43 @@ pub fn delete_receptacle(e: &Env, operator: Address) {
     let key_opr = DataKey::OperatorList;
 
     e.storage().instance().set(&key_opr, &updated_list);
-
-    let consensus_val = read_consensus_threshold(e) - 1;
-    write_consensus_threshold(e, consensus_val);
 }
 
 pub fn read_receptacle_list(e: &Env) -> Vec<ReceptacleDetails> {
     let opr_list = read_operator_list(e);
-    let mut default_list: Vec<ReceptacleDetails> = Vec::new(&e);
-    if opr_list.len() == 0 {
+    let mut default_list: Vec<ReceptacleDetails> = Vec::new(e);
+    if opr_list.is_empty() {
         default_list
     } else {
         for i in 0..opr_list.len() {
             let opr = opr_list.get_unchecked(i);
-            let receptacle_addr = read_receptacle_address(e, opr.clone()).unwrap();
-
-            let receptacle_details = ReceptacleDetails {
-                operator: opr,
-                receptacle_address: receptacle_addr,
-            };
-
-            default_list.push_back(receptacle_details)
+            if let Some(receptacle_addr) = read_receptacle_address(e, opr.clone()) {
+                let receptacle_details = ReceptacleDetails {
+                    operator: opr,
+                    receptacle_address: receptacle_addr,
+                };
+
+                default_list.push_back(receptacle_details)
+            }
         }
         default_list
     }
 }
 
+// Jailed operators keep their receptacle but no longer take part in upkeep.
+pub fn read_active_receptacle_list(e: &Env) -> Vec<ReceptacleDetails> {
+    let mut active_list: Vec<ReceptacleDetails> = Vec::new(e);
+    for receptacle in read_receptacle_list(e).iter() {
+        if !is_jailed(e, &receptacle.operator) {
+            active_list.push_back(receptacle);
+        }
+    }
+    active_list
+}
+
 pub fn read_operator_list(e: &Env) -> Vec<Address> {
     let key = DataKey::OperatorList;
-    let default_list: Vec<Address> = Vec::new(&e);
+    let default_list: Vec<Address> = Vec::new(e);
 
     e.storage()
         .instance()
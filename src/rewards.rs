@@ -0,0 +1,107 @@
+use soroban_sdk::{contracttype, token, Address, Env};
+
+use crate::{data::DataKey, error::ContractError};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct KeeperRewardConfig {
+    pub token: Address,
+    pub reward_per_transfer: i128,
+    pub max_reward_per_call: i128,
+}
+
+pub fn read_keeper_reward_config(e: &Env) -> Option<KeeperRewardConfig> {
+    let key = DataKey::KeeperRewardConfig;
+    e.storage().instance().get(&key)
+}
+
+pub fn write_keeper_reward_config(e: &Env, config: &KeeperRewardConfig) {
+    let key = DataKey::KeeperRewardConfig;
+    e.storage().instance().set(&key, config);
+}
+
+// The pool is held in the reward token, so it can only change once the pool has been emptied.
+pub fn validate_keeper_reward_config(
+    e: &Env,
+    config: &KeeperRewardConfig,
+) -> Result<(), ContractError> {
+    let token_changed = read_keeper_reward_config(e)
+        .is_some_and(|current| current.token != config.token && read_reward_pool(e) > 0);
+    if config.reward_per_transfer <= 0
+        || config.max_reward_per_call < config.reward_per_transfer
+        || token_changed
+    {
+        return Err(ContractError::InvalidKeeperRewardConfig);
+    }
+    Ok(())
+}
+
+pub fn read_keeper_reward_config_or_err(e: &Env) -> Result<KeeperRewardConfig, ContractError> {
+    read_keeper_reward_config(e).ok_or(ContractError::KeeperRewardsNotEnabled)
+}
+
+pub fn read_reward_pool(e: &Env) -> i128 {
+    let key = DataKey::RewardPool;
+    e.storage().instance().get(&key).unwrap_or(0)
+}
+
+fn write_reward_pool(e: &Env, amount: i128) {
+    let key = DataKey::RewardPool;
+    e.storage().instance().set(&key, &amount);
+}
+
+pub fn fund_reward_pool(
+    e: &Env,
+    config: &KeeperRewardConfig,
+    from: &Address,
+    amount: i128,
+) -> Result<i128, ContractError> {
+    if amount <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+
+    token::Client::new(e, &config.token).transfer(from, &e.current_contract_address(), &amount);
+
+    let pool = read_reward_pool(e) + amount;
+    write_reward_pool(e, pool);
+
+    Ok(pool)
+}
+
+pub fn withdraw_reward_pool(
+    e: &Env,
+    config: &KeeperRewardConfig,
+    to: &Address,
+    amount: i128,
+) -> Result<i128, ContractError> {
+    let pool = read_reward_pool(e);
+    if amount <= 0 || amount > pool {
+        return Err(ContractError::InvalidAmount);
+    }
+
+    write_reward_pool(e, pool - amount);
+    token::Client::new(e, &config.token).transfer(&e.current_contract_address(), to, &amount);
+
+    Ok(pool - amount)
+}
+
+// Pays for executed transfers up to the per-call cap, or whatever is left in the pool. Clearing
+// transfers that already executed through pushed or signed votes is housekeeping and earns nothing.
+pub fn pay_keeper_reward(e: &Env, keeper: &Address, executed: u32) -> i128 {
+    let Some(config) = read_keeper_reward_config(e) else {
+        return 0;
+    };
+
+    let pool = read_reward_pool(e);
+    let reward = (config.reward_per_transfer * executed as i128)
+        .min(config.max_reward_per_call)
+        .min(pool);
+    if reward <= 0 {
+        return 0;
+    }
+
+    write_reward_pool(e, pool - reward);
+    token::Client::new(e, &config.token).transfer(&e.current_contract_address(), keeper, &reward);
+
+    reward
+}
@@ -0,0 +1,59 @@
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env};
+
+use crate::{data::DataKey, error::ContractError};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct OperatorSignature {
+    pub public_key: BytesN<32>,
+    pub signature: BytesN<64>,
+}
+
+pub fn read_operator_key(e: &Env, operator: &Address) -> Option<BytesN<32>> {
+    let key = DataKey::OperatorKey(operator.clone());
+    e.storage().instance().get(&key)
+}
+
+pub fn read_key_operator(e: &Env, public_key: &BytesN<32>) -> Option<Address> {
+    let key = DataKey::KeyOperator(public_key.clone());
+    e.storage().instance().get(&key)
+}
+
+// Keys map both ways so a signature can be traced back to a single operator.
+pub fn write_operator_key(
+    e: &Env,
+    operator: &Address,
+    public_key: &BytesN<32>,
+) -> Result<(), ContractError> {
+    if read_key_operator(e, public_key).is_some_and(|owner| owner != *operator) {
+        return Err(ContractError::SignerKeyInUse);
+    }
+    delete_operator_key(e, operator);
+
+    e.storage()
+        .instance()
+        .set(&DataKey::OperatorKey(operator.clone()), public_key);
+    e.storage()
+        .instance()
+        .set(&DataKey::KeyOperator(public_key.clone()), operator);
+
+    Ok(())
+}
+
+pub fn delete_operator_key(e: &Env, operator: &Address) {
+    if let Some(public_key) = read_operator_key(e, operator) {
+        e.storage()
+            .instance()
+            .remove(&DataKey::KeyOperator(public_key));
+        e.storage()
+            .instance()
+            .remove(&DataKey::OperatorKey(operator.clone()));
+    }
+}
+
+// Binding the payload to this contract keeps signatures from being replayed on another deployment.
+pub fn signing_payload(e: &Env, attestation: &Bytes) -> Bytes {
+    let mut payload = e.current_contract_address().to_xdr(e);
+    payload.append(attestation);
+    payload
+}
@@ -0,0 +1,233 @@
+use soroban_sdk::{contracttype, token, Address, Env};
+
+use crate::{
+    consensus::MAX_BPS,
+    data::{DataKey, BUMP_AMOUNT, LIFETIME_THRESHOLD},
+    error::ContractError,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BondConfig {
+    pub token: Address,
+    pub min_bond: i128,
+    pub unbonding_period: u32, //ledgers before unbonded stake can be withdrawn
+    pub min_total_stake: i128, //stake bonded across all operators before votes weigh by stake
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Unbonding {
+    pub amount: i128,
+    pub release_ledger: u32,
+}
+
+pub fn read_bond_config(e: &Env) -> Option<BondConfig> {
+    let key = DataKey::BondConfig;
+    e.storage().instance().get(&key)
+}
+
+pub fn write_bond_config(e: &Env, config: &BondConfig) {
+    let key = DataKey::BondConfig;
+    e.storage().instance().set(&key, config);
+}
+
+pub fn read_bond_config_or_err(e: &Env) -> Result<BondConfig, ContractError> {
+    read_bond_config(e).ok_or(ContractError::StakingNotEnabled)
+}
+
+// The bond token is fixed once staking is enabled since existing bonds are held in it.
+pub fn validate_bond_config(e: &Env, config: &BondConfig) -> Result<(), ContractError> {
+    let token_changed = read_bond_config(e).is_some_and(|current| current.token != config.token);
+    if config.min_bond <= 0 || config.min_total_stake < config.min_bond || token_changed {
+        return Err(ContractError::InvalidBondConfig);
+    }
+    Ok(())
+}
+
+pub fn read_total_stake(e: &Env) -> i128 {
+    let key = DataKey::TotalStake;
+    e.storage().instance().get(&key).unwrap_or(0)
+}
+
+fn write_total_stake(e: &Env, amount: i128) {
+    let key = DataKey::TotalStake;
+    e.storage().instance().set(&key, &amount);
+}
+
+pub fn read_slashed_stake(e: &Env) -> i128 {
+    let key = DataKey::SlashedStake;
+    e.storage().instance().get(&key).unwrap_or(0)
+}
+
+fn write_slashed_stake(e: &Env, amount: i128) {
+    let key = DataKey::SlashedStake;
+    e.storage().instance().set(&key, &amount);
+}
+
+pub fn read_bond(e: &Env, operator: &Address) -> i128 {
+    let key = DataKey::Bond(operator.clone());
+    e.storage().persistent().get(&key).unwrap_or(0)
+}
+
+fn write_bond(e: &Env, operator: &Address, amount: i128) {
+    let key = DataKey::Bond(operator.clone());
+    if amount == 0 {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, &amount);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+    }
+}
+
+pub fn read_unbonding(e: &Env, operator: &Address) -> Option<Unbonding> {
+    let key = DataKey::Unbonding(operator.clone());
+    e.storage().persistent().get(&key)
+}
+
+fn write_unbonding(e: &Env, operator: &Address, unbonding: &Unbonding) {
+    let key = DataKey::Unbonding(operator.clone());
+    e.storage().persistent().set(&key, unbonding);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+fn clear_unbonding(e: &Env, operator: &Address) {
+    let key = DataKey::Unbonding(operator.clone());
+    e.storage().persistent().remove(&key);
+}
+
+// Operators registered before staking hold no bond, so votes keep counting one each until enough
+// stake is bonded that the first operators to bond cannot carry the threshold alone.
+pub fn is_stake_weighted(e: &Env) -> bool {
+    read_bond_config(e).is_some_and(|config| read_total_stake(e) >= config.min_total_stake)
+}
+
+// Votes weigh one each until staking takes over, after which they weigh the operator's bond.
+pub fn read_vote_weight(e: &Env, operator: &Address) -> i128 {
+    if is_stake_weighted(e) {
+        read_bond(e, operator)
+    } else {
+        1
+    }
+}
+
+pub fn deposit_bond(
+    e: &Env,
+    config: &BondConfig,
+    operator: &Address,
+    amount: i128,
+) -> Result<i128, ContractError> {
+    if amount <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    let bond = read_bond(e, operator) + amount;
+    if bond < config.min_bond {
+        return Err(ContractError::InvalidAmount);
+    }
+
+    token::Client::new(e, &config.token).transfer(operator, &e.current_contract_address(), &amount);
+
+    write_bond(e, operator, bond);
+    write_total_stake(e, read_total_stake(e) + amount);
+
+    Ok(bond)
+}
+
+// Moves stake out of the active set; it stays locked until the unbonding period has passed.
+pub fn start_unbonding(
+    e: &Env,
+    config: &BondConfig,
+    operator: &Address,
+    amount: i128,
+) -> Result<Unbonding, ContractError> {
+    let bond = read_bond(e, operator);
+    if amount <= 0 || amount > bond {
+        return Err(ContractError::InvalidAmount);
+    }
+    let remaining = bond - amount;
+    if remaining != 0 && remaining < config.min_bond {
+        return Err(ContractError::InvalidAmount);
+    }
+
+    let pending = read_unbonding(e, operator).map_or(0, |unbonding| unbonding.amount);
+    let unbonding = Unbonding {
+        amount: pending + amount,
+        release_ledger: e.ledger().sequence() + config.unbonding_period,
+    };
+
+    write_bond(e, operator, remaining);
+    write_total_stake(e, read_total_stake(e) - amount);
+    write_unbonding(e, operator, &unbonding);
+
+    Ok(unbonding)
+}
+
+pub fn withdraw_unbonding(
+    e: &Env,
+    config: &BondConfig,
+    operator: &Address,
+) -> Result<i128, ContractError> {
+    let unbonding = read_unbonding(e, operator).ok_or(ContractError::UnbondingNotReady)?;
+    if e.ledger().sequence() < unbonding.release_ledger {
+        return Err(ContractError::UnbondingNotReady);
+    }
+
+    clear_unbonding(e, operator);
+    token::Client::new(e, &config.token).transfer(
+        &e.current_contract_address(),
+        operator,
+        &unbonding.amount,
+    );
+
+    Ok(unbonding.amount)
+}
+
+// Stake that is still unbonding is slashed along with the bond so an operator cannot dodge a
+// fault by unbonding first. Slashed stake stays in the contract until the admin withdraws it.
+pub fn slash_bond(e: &Env, operator: &Address, slash_bps: u32) -> i128 {
+    let bond = read_bond(e, operator);
+    let bond_amount = bond * slash_bps as i128 / MAX_BPS as i128;
+    if bond_amount > 0 {
+        write_bond(e, operator, bond - bond_amount);
+        write_total_stake(e, read_total_stake(e) - bond_amount);
+    }
+
+    let mut unbonding_amount = 0;
+    if let Some(mut unbonding) = read_unbonding(e, operator) {
+        unbonding_amount = unbonding.amount * slash_bps as i128 / MAX_BPS as i128;
+        unbonding.amount -= unbonding_amount;
+        if unbonding.amount == 0 {
+            clear_unbonding(e, operator);
+        } else if unbonding_amount > 0 {
+            write_unbonding(e, operator, &unbonding);
+        }
+    }
+
+    let amount = bond_amount + unbonding_amount;
+    if amount > 0 {
+        write_slashed_stake(e, read_slashed_stake(e) + amount);
+    }
+
+    amount
+}
+
+pub fn withdraw_slashed_stake(
+    e: &Env,
+    config: &BondConfig,
+    to: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let slashed = read_slashed_stake(e);
+    if amount <= 0 || amount > slashed {
+        return Err(ContractError::InvalidAmount);
+    }
+
+    write_slashed_stake(e, slashed - amount);
+    token::Client::new(e, &config.token).transfer(&e.current_contract_address(), to, &amount);
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::{
+    data::{DataKey, BUMP_AMOUNT, LIFETIME_THRESHOLD},
+    receptacle_ids::read_operator_list,
+};
+
+pub(crate) const MAX_STATS_PAGE: u32 = 50;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[contracttype]
+pub struct OperatorStats {
+    pub agreed: u32,    //attestations matching the executed transfer
+    pub disagreed: u32, //attestations conflicting with it
+    pub missed: u32,    //executed transfers the operator never attested to
+    pub last_active_ledger: u32,
+}
+
+pub fn read_operator_stats(e: &Env, operator: &Address) -> OperatorStats {
+    let key = DataKey::OperatorStats(operator.clone());
+    e.storage().persistent().get(&key).unwrap_or_default()
+}
+
+pub fn write_operator_stats(e: &Env, operator: &Address, stats: &OperatorStats) {
+    let key = DataKey::OperatorStats(operator.clone());
+    e.storage().persistent().set(&key, stats);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+pub fn record_attestation(e: &Env, operator: &Address, agreed: bool) {
+    let mut stats = read_operator_stats(e, operator);
+    if agreed {
+        stats.agreed += 1;
+    } else {
+        stats.disagreed += 1;
+    }
+    stats.last_active_ledger = e.ledger().sequence();
+    write_operator_stats(e, operator, &stats);
+}
+
+pub fn record_missed(e: &Env, operator: &Address) {
+    let mut stats = read_operator_stats(e, operator);
+    stats.missed += 1;
+    write_operator_stats(e, operator, &stats);
+}
+
+// Replaces a missed record with the attestation the operator delivered after execution.
+pub fn record_late_attestation(e: &Env, operator: &Address, agreed: bool) {
+    let mut stats = read_operator_stats(e, operator);
+    stats.missed = stats.missed.saturating_sub(1);
+    write_operator_stats(e, operator, &stats);
+    record_attestation(e, operator, agreed);
+}
+
+// Pages follow the registration order of the operator list.
+pub fn read_operator_stats_page(e: &Env, start: u32, limit: u32) -> Vec<(Address, OperatorStats)> {
+    let operators = read_operator_list(e);
+    let end = u32::min(
+        start.saturating_add(u32::min(limit, MAX_STATS_PAGE)),
+        operators.len(),
+    );
+
+    let mut page = Vec::new(e);
+    for i in start..end {
+        let operator = operators.get_unchecked(i);
+        let stats = read_operator_stats(e, &operator);
+        page.push_back((operator, stats));
+    }
+    page
+}
@@ -0,0 +1,2813 @@
+#![cfg(test)]
+extern crate std;
+
+use ed25519_dalek::{Signer, SigningKey};
+use k256::ecdsa::SigningKey as EvmSigningKey;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
+    token::{StellarAssetClient, TokenClient},
+    vec,
+    xdr::ToXdr,
+    Address, Bytes, BytesN, Env, FromVal, IntoVal, InvokeError, String, Vec,
+};
+
+use crate::{
+    consensus::{read_consensus_threshold, threshold_validation, ConsensusPolicy},
+    contract::{Oracle, OracleClient},
+    council::ProposalAction,
+    data::{DAY_IN_LEDGERS, PROPOSAL_LIFETIME},
+    error::ContractError,
+    faults::SlashingConfig,
+    mock_bridge::{
+        BridgeCall, MockBridge, MockBridgeClient, EXECUTE_EVM_TO_SOROBAN, EXECUTE_REBALANCING,
+        TRANSFER_SOROBAN_TO_EVM,
+    },
+    oracle_receptacle::{self, ReceptacleError},
+    rewards::KeeperRewardConfig,
+    signers::OperatorSignature,
+    staking::BondConfig,
+    stats::OperatorStats,
+    types::SourceSignature,
+    upgrade::PendingUpgradeDelay,
+    upkeep::{TransferVotes, UpkeepConfig, UpkeepOutcome, UpkeepSummary},
+};
+
+const CHAIN_ID: u32 = 1;
+
+struct Setup<'a> {
+    e: Env,
+    admin: Address,
+    manager: Address,
+    oracle: OracleClient<'a>,
+    bridge: MockBridgeClient<'a>,
+    token: Address,
+    keeper: Address,
+}
+
+struct Node<'a> {
+    operator: Address,
+    receptacle: oracle_receptacle::Client<'a>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let token = Address::generate(&e);
+    let keeper = Address::generate(&e);
+
+    let oracle = OracleClient::new(&e, &e.register(Oracle, ()));
+    let bridge = MockBridgeClient::new(&e, &e.register(MockBridge, ()));
+
+    oracle.initialize(&admin);
+    oracle.setup_bridge(&bridge.address);
+    oracle.add_manager(&manager);
+    oracle.add_new_chain(
+        &manager,
+        &String::from_str(&e, "Ethereum"),
+        &CHAIN_ID,
+        &String::from_str(&e, "0x5f4ec3df9cbd43714fe2740f5e3616155c5b8419"),
+    );
+    oracle.add_token_destination_map(
+        &manager,
+        &token,
+        &CHAIN_ID,
+        &String::from_str(&e, "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
+    );
+
+    Setup {
+        e,
+        admin,
+        manager,
+        oracle,
+        bridge,
+        token,
+        keeper,
+    }
+}
+
+fn setup_nodes<'a>(s: &Setup<'a>, count: u32) -> std::vec::Vec<Node<'a>> {
+    let mut nodes = std::vec::Vec::new();
+    for _ in 0..count {
+        let operator = Address::generate(&s.e);
+        let receptacle_address = s.oracle.setup_new_node(&operator);
+        nodes.push(Node {
+            operator,
+            receptacle: oracle_receptacle::Client::new(&s.e, &receptacle_address),
+        });
+    }
+    nodes
+}
+
+fn copy_strkey(address: &Address, payload: &mut [u8], length_index: usize) {
+    let strkey = address.to_string();
+    let length = strkey.len() as usize;
+    payload[length_index] = length as u8;
+    strkey.copy_into_slice(&mut payload[length_index + 1..length_index + 1 + length]);
+}
+
+// ABI-style payload as emitted by the EVM side: the amount as a 32-byte word, followed by the
+// recipient and token strkeys as length-prefixed dynamic strings.
+fn encode_evm_payload(e: &Env, recipient: &Address, token: &Address, amount: i128) -> Bytes {
+    let mut payload = [0u8; 320];
+    payload[112..128].copy_from_slice(&amount.to_be_bytes());
+    copy_strkey(recipient, &mut payload, 159);
+    copy_strkey(token, &mut payload, 255);
+    Bytes::from_slice(e, &payload)
+}
+
+fn tx_id(e: &Env, id: u8) -> Bytes {
+    Bytes::from_array(e, &[id; 32])
+}
+
+fn commit(s: &Setup, node: &Node, tx_id: &Bytes, recipient: &Address, amount: i128) {
+    node.receptacle.commit_transfer(
+        tx_id,
+        &CHAIN_ID,
+        &String::from_str(&s.e, "0x000000000000000000000000000000000000beef"),
+        &encode_evm_payload(&s.e, recipient, &s.token, amount),
+    );
+}
+
+fn pending_transfers(s: &Setup, node: &Node) -> Vec<Bytes> {
+    Vec::from_val(&s.e, &node.receptacle.get_transfer_id_list())
+}
+
+fn inbound_call(s: &Setup, recipient: &Address, amount: i128) -> BridgeCall {
+    BridgeCall {
+        function: EXECUTE_EVM_TO_SOROBAN,
+        account: recipient.clone(),
+        token: s.token.clone(),
+        amount,
+    }
+}
+
+#[test]
+fn test_initialize() {
+    let s = setup();
+
+    assert_eq!(s.oracle.get_admin(), s.admin);
+    assert_eq!(
+        s.oracle.try_initialize(&Address::generate(&s.e)),
+        Err(Ok(ContractError::AlreadyInitialized))
+    );
+    assert_eq!(s.oracle.get_consensus_threshold(), 0);
+    assert!(s.oracle.get_receptacle_list().is_empty());
+}
+
+#[test]
+fn test_get_admin_before_initialize() {
+    let e = Env::default();
+    let oracle = OracleClient::new(&e, &e.register(Oracle, ()));
+
+    assert_eq!(
+        oracle.try_get_admin(),
+        Err(Ok(ContractError::NotInitialized))
+    );
+}
+
+#[test]
+fn test_admin_handover() {
+    let s = setup();
+    let new_admin = Address::generate(&s.e);
+
+    assert_eq!(
+        s.oracle.try_accept_admin(),
+        Err(Ok(ContractError::NoPendingAdmin))
+    );
+    assert_eq!(
+        s.oracle.try_cancel_admin_proposal(),
+        Err(Ok(ContractError::NoPendingAdmin))
+    );
+
+    // A cancelled proposal can no longer be accepted.
+    s.oracle.propose_admin(&Address::generate(&s.e));
+    s.oracle.cancel_admin_proposal();
+    assert!(s.oracle.get_pending_admin().is_none());
+    assert_eq!(
+        s.oracle.try_accept_admin(),
+        Err(Ok(ContractError::NoPendingAdmin))
+    );
+
+    // The current admin stays in charge until the proposed one accepts.
+    s.oracle.propose_admin(&new_admin);
+    assert_eq!(s.oracle.get_pending_admin(), Some(new_admin.clone()));
+    assert_eq!(s.oracle.get_admin(), s.admin);
+
+    s.oracle.accept_admin();
+    assert_eq!(s.e.auths()[0].0, new_admin);
+    assert_eq!(s.oracle.get_admin(), new_admin);
+    assert!(s.oracle.get_pending_admin().is_none());
+
+    s.oracle.add_manager(&Address::generate(&s.e));
+    assert_eq!(s.e.auths()[0].0, new_admin);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_accept_admin_requires_proposed_admin_auth() {
+    let s = setup();
+    s.oracle.propose_admin(&Address::generate(&s.e));
+
+    s.e.set_auths(&[]);
+    s.oracle.accept_admin();
+}
+
+#[test]
+fn test_setup_new_node_deploys_receptacle() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 3);
+
+    let list = s.oracle.get_receptacle_list();
+    assert_eq!(list.len(), 3);
+    for (i, node) in nodes.iter().enumerate() {
+        let details = list.get_unchecked(i as u32);
+        assert_eq!(details.operator, node.operator);
+        assert_eq!(details.receptacle_address, node.receptacle.address);
+        assert_eq!(
+            s.oracle.get_receptacle_address(&node.operator),
+            node.receptacle.address
+        );
+        assert_eq!(node.receptacle.get_payer(), node.operator);
+    }
+    assert_eq!(s.oracle.get_consensus_threshold(), 3);
+
+    assert_eq!(
+        s.oracle.try_setup_new_node(&nodes[0].operator),
+        Err(Ok(ContractError::AlreadyNodeOperator))
+    );
+    assert_eq!(
+        s.oracle
+            .try_get_receptacle_address(&Address::generate(&s.e)),
+        Err(Ok(ContractError::ReceptacleNotFound))
+    );
+}
+
+#[test]
+fn test_commit_transfer_stores_decoded_payload() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 2);
+    let recipient = Address::generate(&s.e);
+    let tx = tx_id(&s.e, 1);
+
+    commit(&s, &nodes[0], &tx, &recipient, 1_234_567);
+
+    assert_eq!(pending_transfers(&s, &nodes[0]), vec![&s.e, tx.clone()]);
+    assert!(s.oracle.get_upkeep_required());
+
+    let data: Vec<Bytes> = Vec::from_val(&s.e, &nodes[0].receptacle.get_transfer(&tx));
+    assert_eq!(data.len(), 6);
+    assert_eq!(data.get_unchecked(0), tx);
+    assert_eq!(data.get_unchecked(3), recipient.clone().to_xdr(&s.e));
+    assert_eq!(data.get_unchecked(4), s.token.clone().to_xdr(&s.e));
+    assert_eq!(data.get_unchecked(5), 1_234_567i128.to_xdr(&s.e));
+}
+
+#[test]
+fn test_perform_upkeep_reaches_consensus() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 3);
+    s.oracle.set_push_enabled(&false);
+    let recipient = Address::generate(&s.e);
+    let tx = tx_id(&s.e, 1);
+
+    for node in nodes.iter() {
+        commit(&s, node, &tx, &recipient, 1_000);
+    }
+
+    assert!(s.bridge.get_calls().is_empty());
+    assert!(s.oracle.get_pending_votes(&tx).is_empty());
+    assert_eq!(s.oracle.perform_upkeep(&s.keeper).processed, 1);
+
+    assert_eq!(
+        s.bridge.get_calls(),
+        vec![&s.e, inbound_call(&s, &recipient, 1_000)]
+    );
+    for node in nodes.iter() {
+        assert!(pending_transfers(&s, node).is_empty());
+        let data: Vec<Bytes> = Vec::from_val(&s.e, &node.receptacle.get_transfer(&tx));
+        assert!(data.is_empty());
+    }
+    assert!(!s.oracle.get_upkeep_required());
+}
+
+#[test]
+fn test_perform_upkeep_processes_batch_of_transfers() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 2);
+    s.oracle.set_push_enabled(&false);
+    let recipient = Address::generate(&s.e);
+
+    for id in 1..=3 {
+        for node in nodes.iter() {
+            commit(&s, node, &tx_id(&s.e, id), &recipient, id as i128 * 100);
+        }
+    }
+
+    assert!(s.bridge.get_calls().is_empty());
+    assert_eq!(s.oracle.perform_upkeep(&s.keeper).processed, 3);
+
+    assert_eq!(
+        s.bridge.get_calls(),
+        vec![
+            &s.e,
+            inbound_call(&s, &recipient, 100),
+            inbound_call(&s, &recipient, 200),
+            inbound_call(&s, &recipient, 300),
+        ]
+    );
+    for node in nodes.iter() {
+        assert!(pending_transfers(&s, node).is_empty());
+    }
+}
+
+#[test]
+fn test_perform_upkeep_without_consensus_when_node_missing() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 3);
+    s.oracle.set_push_enabled(&false);
+    let recipient = Address::generate(&s.e);
+    let tx = tx_id(&s.e, 1);
+
+    commit(&s, &nodes[0], &tx, &recipient, 1_000);
+    commit(&s, &nodes[1], &tx, &recipient, 1_000);
+
+    s.oracle.perform_upkeep(&s.keeper);
+
+    assert!(s.bridge.get_calls().is_empty());
+    assert_eq!(pending_transfers(&s, &nodes[0]), vec![&s.e, tx.clone()]);
+    assert_eq!(pending_transfers(&s, &nodes[1]), vec![&s.e, tx.clone()]);
+
+    // The late node catches up and the next upkeep executes the transfer.
+    commit(&s, &nodes[2], &tx, &recipient, 1_000);
+    assert_eq!(s.oracle.perform_upkeep(&s.keeper).processed, 1);
+
+    assert_eq!(
+        s.bridge.get_calls(),
+        vec![&s.e, inbound_call(&s, &recipient, 1_000)]
+    );
+    for node in nodes.iter() {
+        assert!(pending_transfers(&s, node).is_empty());
+    }
+}
+
+#[test]
+fn test_perform_upkeep_with_disagreeing_nodes() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 3);
+    s.oracle.set_push_enabled(&false);
+    let recipient = Address::generate(&s.e);
+    let tx = tx_id(&s.e, 1);
+
+    commit(&s, &nodes[0], &tx, &recipient, 1_000);
+    commit(&s, &nodes[1], &tx, &recipient, 1_000);
+    commit(&s, &nodes[2], &tx, &recipient, 9_999);
+
+    s.oracle.perform_upkeep(&s.keeper);
+
+    assert!(s.bridge.get_calls().is_empty());
+    for node in nodes.iter() {
+        assert_eq!(pending_transfers(&s, node), vec![&s.e, tx.clone()]);
+    }
+
+    // A different recipient is a disagreement as well.
+    let other_tx = tx_id(&s.e, 2);
+    commit(&s, &nodes[0], &other_tx, &recipient, 1_000);
+    commit(&s, &nodes[1], &other_tx, &recipient, 1_000);
+    commit(&s, &nodes[2], &other_tx, &Address::generate(&s.e), 1_000);
+
+    s.oracle.perform_upkeep(&s.keeper);
+    assert!(s.bridge.get_calls().is_empty());
+}
+
+#[test]
+fn test_perform_upkeep_does_not_leak_votes_between_runs() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 2);
+    s.oracle.set_push_enabled(&false);
+    let recipient = Address::generate(&s.e);
+    let tx = tx_id(&s.e, 1);
+
+    commit(&s, &nodes[0], &tx, &recipient, 1_000);
+
+    // Repeated upkeeps with a single vote must never add up to the threshold of two.
+    s.oracle.perform_upkeep(&s.keeper);
+    s.oracle.perform_upkeep(&s.keeper);
+    s.oracle.perform_upkeep(&s.keeper);
+
+    assert!(s.bridge.get_calls().is_empty());
+}
+
+#[test]
+fn test_perform_upkeep_bridge_failure_keeps_transfer_queued() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 2);
+    s.oracle.set_push_enabled(&false);
+    let recipient = Address::generate(&s.e);
+    let tx = tx_id(&s.e, 1);
+
+    for node in nodes.iter() {
+        commit(&s, node, &tx, &recipient, 1_000);
+    }
+
+    let other_recipient = Address::generate(&s.e);
+    let other = tx_id(&s.e, 2);
+    for node in nodes.iter() {
+        commit(&s, node, &other, &other_recipient, 2_000);
+    }
+
+    // A failed bridge call is reported for that transfer alone and leaves it queued.
+    s.bridge.set_failing_account(&recipient, &true);
+    assert_eq!(
+        s.oracle.perform_upkeep(&s.keeper),
+        UpkeepSummary {
+            processed: 1,
+            skipped: 0,
+            failed: 1,
+            remaining: 0,
+            reward: 0,
+        }
+    );
+    assert_eq!(
+        s.bridge.get_calls(),
+        vec![&s.e, inbound_call(&s, &other_recipient, 2_000)]
+    );
+    for node in nodes.iter() {
+        assert_eq!(pending_transfers(&s, node), vec![&s.e, tx.clone()]);
+    }
+    assert!(!s.oracle.get_transfer_processed(&tx));
+
+    // Keepers are no longer pointed at the failed transfer, but can see why.
+    let check = s.oracle.check_upkeep();
+    assert!(!check.upkeep_needed);
+    assert_eq!(
+        check.transfers.get_unchecked(0).last_failure,
+        Some(s.e.ledger().sequence())
+    );
+
+    let unknown = tx_id(&s.e, 3);
+    assert_eq!(
+        s.oracle
+            .perform_upkeep_for(&s.keeper, &vec![&s.e, tx.clone(), unknown.clone()]),
+        vec![
+            &s.e,
+            (tx.clone(), UpkeepOutcome::Failed),
+            (unknown, UpkeepOutcome::NotFound)
+        ]
+    );
+
+    // The regular upkeep keeps retrying it.
+    s.bridge.set_failing_account(&recipient, &false);
+    assert_eq!(s.oracle.perform_upkeep(&s.keeper).processed, 1);
+    assert_eq!(s.bridge.get_calls().len(), 2);
+    assert!(s.oracle.check_upkeep().transfers.is_empty());
+}
+
+#[test]
+fn test_perform_upkeep_without_receptacles() {
+    let s = setup();
+
+    assert_eq!(
+        s.oracle.try_perform_upkeep(&s.keeper),
+        Err(Ok(ContractError::NoReceptacles))
+    );
+    assert!(!s.oracle.get_upkeep_required());
+}
+
+#[test]
+fn test_remove_node_decrements_threshold() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 3);
+    s.oracle.set_push_enabled(&false);
+    let recipient = Address::generate(&s.e);
+    let tx = tx_id(&s.e, 1);
+
+    s.oracle.remove_node(&nodes[2].operator);
+
+    assert_eq!(s.oracle.get_consensus_threshold(), 2);
+    assert_eq!(s.oracle.get_receptacle_list().len(), 2);
+    assert_eq!(
+        s.oracle.try_get_receptacle_address(&nodes[2].operator),
+        Err(Ok(ContractError::ReceptacleNotFound))
+    );
+    assert_eq!(
+        s.oracle.try_remove_node(&nodes[2].operator),
+        Err(Ok(ContractError::NodeNotFound))
+    );
+
+    // The remaining two nodes now reach consensus on their own.
+    commit(&s, &nodes[0], &tx, &recipient, 1_000);
+    commit(&s, &nodes[1], &tx, &recipient, 1_000);
+    assert_eq!(s.oracle.perform_upkeep(&s.keeper).processed, 1);
+
+    assert_eq!(
+        s.bridge.get_calls(),
+        vec![&s.e, inbound_call(&s, &recipient, 1_000)]
+    );
+}
+
+#[test]
+fn test_token_and_chain_maps() {
+    let s = setup();
+    let second_chain = 56;
+
+    s.oracle.add_new_chain(
+        &s.manager,
+        &String::from_str(&s.e, "BNB Chain"),
+        &second_chain,
+        &String::from_str(&s.e, "0x0567f2323251f0aab15c8dfb1967e4e8a7d42aee"),
+    );
+    assert_eq!(
+        s.oracle.try_add_new_chain(
+            &s.manager,
+            &String::from_str(&s.e, "BNB Chain"),
+            &second_chain,
+            &String::from_str(&s.e, "0x0567f2323251f0aab15c8dfb1967e4e8a7d42aee"),
+        ),
+        Err(Ok(ContractError::ChainAlreadyAdded))
+    );
+    assert_eq!(s.oracle.get_all_supported_chains().len(), 2);
+
+    let destination_token = String::from_str(&s.e, "0x8ac76a51cc950d9822d68b83fe1ad97b32cd580d");
+    s.oracle
+        .add_token_destination_map(&s.manager, &s.token, &second_chain, &destination_token);
+
+    let details = s
+        .oracle
+        .get_destination_chain_token(&s.token, &second_chain);
+    assert_eq!(details.chain_id, second_chain);
+    assert_eq!(details.chain_name, String::from_str(&s.e, "BNB Chain"));
+    assert_eq!(details.destination_token, destination_token);
+    assert_eq!(s.oracle.get_destination_chain_list(&s.token).len(), 2);
+
+    s.oracle
+        .remove_token_destination_map(&s.manager, &s.token, &second_chain);
+    assert_eq!(
+        s.oracle
+            .try_get_destination_chain_token(&s.token, &second_chain),
+        Err(Ok(ContractError::TokenChainMapNotFound))
+    );
+    assert_eq!(s.oracle.get_destination_chain_list(&s.token).len(), 1);
+
+    s.oracle.remove_chain(&s.manager, &second_chain);
+    assert_eq!(s.oracle.get_all_supported_chains().len(), 1);
+    assert_eq!(
+        s.oracle.try_remove_chain(&s.manager, &second_chain),
+        Err(Ok(ContractError::ChainNotFound))
+    );
+    assert_eq!(
+        s.oracle.try_add_token_destination_map(
+            &s.manager,
+            &s.token,
+            &second_chain,
+            &destination_token
+        ),
+        Err(Ok(ContractError::ChainNotFound))
+    );
+    assert_eq!(
+        s.oracle
+            .try_get_destination_chain_list(&Address::generate(&s.e)),
+        Err(Ok(ContractError::TokenChainMapNotFound))
+    );
+}
+
+#[test]
+fn test_outgoing_transfer_calls_bridge() {
+    let s = setup();
+    let user = Address::generate(&s.e);
+    let recipient = String::from_str(&s.e, "0x000000000000000000000000000000000000dead");
+
+    s.oracle
+        .initiate_outgoing_transfer(&user, &CHAIN_ID, &recipient, &s.token, &1_000);
+
+    assert_eq!(
+        s.bridge.get_calls(),
+        vec![
+            &s.e,
+            BridgeCall {
+                function: TRANSFER_SOROBAN_TO_EVM,
+                account: user,
+                token: s.token.clone(),
+                amount: 1_000,
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_rebalancing_calls_bridge() {
+    let s = setup();
+    let user = Address::generate(&s.e);
+    let recipient = String::from_str(&s.e, "0x000000000000000000000000000000000000dead");
+
+    s.oracle
+        .initiate_rebalancing(&user, &CHAIN_ID, &recipient, &s.token, &500);
+
+    assert_eq!(
+        s.bridge.get_calls(),
+        vec![
+            &s.e,
+            BridgeCall {
+                function: EXECUTE_REBALANCING,
+                account: user,
+                token: s.token.clone(),
+                amount: 500,
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_outgoing_transfer_fails_when_bridge_fails() {
+    let s = setup();
+    let user = Address::generate(&s.e);
+    let recipient = String::from_str(&s.e, "0x000000000000000000000000000000000000dead");
+
+    s.bridge.set_failing(&TRANSFER_SOROBAN_TO_EVM, &true);
+
+    assert!(s
+        .oracle
+        .try_initiate_outgoing_transfer(&user, &CHAIN_ID, &recipient, &s.token, &1_000)
+        .is_err());
+    assert!(s.bridge.get_calls().is_empty());
+
+    s.bridge.set_failing(&TRANSFER_SOROBAN_TO_EVM, &false);
+    s.oracle
+        .initiate_outgoing_transfer(&user, &CHAIN_ID, &recipient, &s.token, &1_000);
+    assert_eq!(s.bridge.get_calls().len(), 1);
+}
+
+#[test]
+fn test_outgoing_transfer_without_bridge() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let oracle = OracleClient::new(&e, &e.register(Oracle, ()));
+    oracle.initialize(&Address::generate(&e));
+
+    let result = oracle.try_initiate_outgoing_transfer(
+        &Address::generate(&e),
+        &CHAIN_ID,
+        &String::from_str(&e, "0x000000000000000000000000000000000000dead"),
+        &Address::generate(&e),
+        &1_000,
+    );
+    assert_eq!(result, Err(Ok(ContractError::BridgeNotFound)));
+}
+
+#[test]
+fn test_manager_entry_points_reject_non_managers() {
+    let s = setup();
+    let outsider = Address::generate(&s.e);
+
+    assert_eq!(
+        s.oracle.try_add_new_chain(
+            &outsider,
+            &String::from_str(&s.e, "BNB Chain"),
+            &56,
+            &String::from_str(&s.e, "0x0567f2323251f0aab15c8dfb1967e4e8a7d42aee"),
+        ),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(
+        s.oracle.try_remove_chain(&outsider, &CHAIN_ID),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(
+        s.oracle
+            .try_remove_token_destination_map(&outsider, &s.token, &CHAIN_ID),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    s.oracle.remove_manager(&s.manager);
+    assert_eq!(
+        s.oracle.try_remove_chain(&s.manager, &CHAIN_ID),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    // The admin manages chains without joining the manager set.
+    assert!(!s.oracle.get_managers().contains(&s.admin));
+    s.oracle.set_chain_active(&s.admin, &CHAIN_ID, &false);
+    assert_eq!(s.e.auths()[0].0, s.admin);
+    s.oracle.remove_chain(&s.admin, &CHAIN_ID);
+    assert!(s.oracle.get_all_supported_chains().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_setup_new_node_requires_admin_auth() {
+    let e = Env::default();
+    let oracle = OracleClient::new(&e, &e.register(Oracle, ()));
+    oracle.initialize(&Address::generate(&e));
+
+    oracle.setup_new_node(&Address::generate(&e));
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_remove_node_requires_admin_auth() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 1);
+
+    s.e.set_auths(&[]);
+    s.oracle.remove_node(&nodes[0].operator);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_add_new_chain_requires_manager_auth() {
+    let s = setup();
+
+    s.e.set_auths(&[]);
+    s.oracle.add_new_chain(
+        &s.manager,
+        &String::from_str(&s.e, "BNB Chain"),
+        &56,
+        &String::from_str(&s.e, "0x0567f2323251f0aab15c8dfb1967e4e8a7d42aee"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_commit_transfer_requires_operator_auth() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 1);
+
+    s.e.set_auths(&[]);
+    commit(
+        &s,
+        &nodes[0],
+        &tx_id(&s.e, 1),
+        &Address::generate(&s.e),
+        1_000,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_remove_transfer_requires_oracle_auth() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 1);
+    let tx = tx_id(&s.e, 1);
+    commit(&s, &nodes[0], &tx, &Address::generate(&s.e), 1_000);
+
+    s.e.set_auths(&[]);
+    nodes[0].receptacle.remove_transfer(&tx);
+}
+
+#[test]
+fn test_absolute_consensus_policy_tolerates_offline_node() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 3);
+    s.oracle.set_push_enabled(&false);
+    let recipient = Address::generate(&s.e);
+    let tx = tx_id(&s.e, 1);
+
+    s.oracle.set_consensus_policy(&ConsensusPolicy::Absolute(2));
+    assert_eq!(
+        s.oracle.get_consensus_policy(),
+        ConsensusPolicy::Absolute(2)
+    );
+    assert_eq!(s.oracle.get_consensus_threshold(), 2);
+
+    commit(&s, &nodes[0], &tx, &recipient, 1_000);
+    commit(&s, &nodes[1], &tx, &recipient, 1_000);
+    assert_eq!(s.oracle.perform_upkeep(&s.keeper).processed, 1);
+
+    assert_eq!(
+        s.bridge.get_calls(),
+        vec![&s.e, inbound_call(&s, &recipient, 1_000)]
+    );
+
+    // A fourth node would leave two votes short of a majority, so the count goes up first.
+    assert_eq!(
+        s.oracle.try_setup_new_node(&Address::generate(&s.e)),
+        Err(Ok(ContractError::InvalidConsensusPolicy))
+    );
+
+    s.oracle.set_consensus_policy(&ConsensusPolicy::Absolute(3));
+    setup_nodes(&s, 1);
+    assert_eq!(s.oracle.get_consensus_threshold(), 3);
+}
+
+#[test]
+fn test_fractional_consensus_policy() {
+    let s = setup();
+    setup_nodes(&s, 6);
+
+    // Default policy requires every operator.
+    assert_eq!(s.oracle.get_consensus_threshold(), 6);
+
+    s.oracle
+        .set_consensus_policy(&ConsensusPolicy::Fraction(6_667));
+    assert_eq!(s.oracle.get_consensus_threshold(), 5);
+
+    setup_nodes(&s, 3);
+    assert_eq!(s.oracle.get_consensus_threshold(), 7);
+}
+
+#[test]
+fn test_invalid_consensus_policies() {
+    let s = setup();
+    setup_nodes(&s, 4);
+
+    for policy in [
+        ConsensusPolicy::Absolute(0),
+        ConsensusPolicy::Absolute(2),
+        ConsensusPolicy::Absolute(5),
+        ConsensusPolicy::Fraction(5_000),
+        ConsensusPolicy::Fraction(10_001),
+    ] {
+        assert_eq!(
+            s.oracle.try_set_consensus_policy(&policy),
+            Err(Ok(ContractError::InvalidConsensusPolicy))
+        );
+    }
+}
+
+#[test]
+fn test_remove_node_keeps_absolute_threshold_reachable() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 3);
+
+    s.oracle.set_consensus_policy(&ConsensusPolicy::Absolute(3));
+    assert_eq!(
+        s.oracle.try_remove_node(&nodes[0].operator),
+        Err(Ok(ContractError::InvalidConsensusPolicy))
+    );
+
+    s.oracle.set_consensus_policy(&ConsensusPolicy::Absolute(2));
+    s.oracle.remove_node(&nodes[0].operator);
+    assert_eq!(s.oracle.get_consensus_threshold(), 2);
+}
+
+#[test]
+fn test_empty_attestations_never_reach_consensus() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 3);
+    s.oracle.set_push_enabled(&false);
+    let recipient = Address::generate(&s.e);
+    let tx = tx_id(&s.e, 1);
+
+    // Two nodes that never saw the transfer must not outvote the one that did.
+    s.oracle.set_consensus_policy(&ConsensusPolicy::Absolute(2));
+    commit(&s, &nodes[0], &tx, &recipient, 1_000);
+
+    assert_eq!(
+        s.oracle.perform_upkeep(&s.keeper),
+        UpkeepSummary {
+            processed: 0,
+            skipped: 1,
+            failed: 0,
+            remaining: 0,
+            reward: 0,
+        }
+    );
+    assert!(s.bridge.get_calls().is_empty());
+    assert_eq!(pending_transfers(&s, &nodes[0]), vec![&s.e, tx.clone()]);
+}
+
+#[test]
+fn test_consensus_quorum_requires_participation() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 3);
+    s.oracle.set_push_enabled(&false);
+    let recipient = Address::generate(&s.e);
+    let tx = tx_id(&s.e, 1);
+
+    assert_eq!(s.oracle.get_consensus_quorum(), 1);
+    for quorum in [0, 4] {
+        assert_eq!(
+            s.oracle.try_set_consensus_quorum(&quorum),
+            Err(Ok(ContractError::InvalidConsensusPolicy))
+        );
+    }
+
+    s.oracle.set_consensus_policy(&ConsensusPolicy::Absolute(2));
+    s.oracle.set_consensus_quorum(&3);
+
+    commit(&s, &nodes[0], &tx, &recipient, 1_000);
+    commit(&s, &nodes[1], &tx, &recipient, 1_000);
+    s.oracle.perform_upkeep(&s.keeper);
+    assert!(s.bridge.get_calls().is_empty());
+
+    // Once every node has attested, two agreeing votes are enough.
+    commit(&s, &nodes[2], &tx, &recipient, 9_999);
+    assert_eq!(s.oracle.perform_upkeep(&s.keeper).processed, 1);
+    assert_eq!(
+        s.bridge.get_calls(),
+        vec![&s.e, inbound_call(&s, &recipient, 1_000)]
+    );
+
+    assert_eq!(
+        s.oracle.try_remove_node(&nodes[0].operator),
+        Err(Ok(ContractError::InvalidConsensusPolicy))
+    );
+}
+
+// The storage-backed tally this contract used before, kept here as a cost baseline.
+fn storage_tally(e: &Env, data_list: &Vec<Bytes>) -> Option<Bytes> {
+    let consensus_threshold = read_consensus_threshold(e);
+    let mut distinct_data_list: Vec<Bytes> = Vec::new(e);
+    for tx_data in data_list.iter() {
+        let key = (symbol_short!("count"), tx_data.clone());
+        let count: u32 = e.storage().instance().get(&key).unwrap_or(0) + 1;
+        if !distinct_data_list.contains(tx_data.clone()) {
+            distinct_data_list.push_back(tx_data.clone());
+        }
+        e.storage().instance().set(&key, &count);
+    }
+
+    let mut validated = None;
+    for tx_data in distinct_data_list.iter() {
+        let key = (symbol_short!("count"), tx_data.clone());
+        let count: u32 = e.storage().instance().get(&key).unwrap_or(0);
+        if count >= consensus_threshold {
+            validated = Some(tx_data);
+            break;
+        }
+    }
+
+    for tx_data in data_list.iter() {
+        e.storage()
+            .instance()
+            .remove(&(symbol_short!("count"), tx_data));
+    }
+    validated
+}
+
+#[test]
+fn test_in_memory_tally_budget() {
+    for node_count in [5, 10, 20] {
+        let s = setup();
+        setup_nodes(&s, node_count);
+        let recipient = Address::generate(&s.e);
+
+        let attestation: Vec<Bytes> = vec![
+            &s.e,
+            tx_id(&s.e, 1),
+            CHAIN_ID.to_xdr(&s.e),
+            String::from_str(&s.e, "0x000000000000000000000000000000000000beef").to_xdr(&s.e),
+            recipient.clone().to_xdr(&s.e),
+            s.token.clone().to_xdr(&s.e),
+            1_000_i128.to_xdr(&s.e),
+        ];
+        let mut data_list: Vec<Bytes> = Vec::new(&s.e);
+        let mut votes: Vec<(Bytes, i128)> = Vec::new(&s.e);
+        for _ in 0..node_count {
+            data_list.push_back(attestation.clone().to_xdr(&s.e));
+            votes.push_back((attestation.clone().to_xdr(&s.e), 1));
+        }
+
+        // Measure whole invocations so the storage baseline also pays for writing the instance back.
+        let mut budget = s.e.cost_estimate().budget();
+
+        budget.reset_default();
+        let validated = s.e.as_contract(&s.oracle.address, || {
+            threshold_validation(&s.e, votes.clone())
+        });
+        let tally_cpu = budget.cpu_instruction_cost();
+        let tally_mem = budget.memory_bytes_cost();
+
+        budget.reset_default();
+        let baseline =
+            s.e.as_contract(&s.oracle.address, || storage_tally(&s.e, &data_list));
+        let storage_cpu = budget.cpu_instruction_cost();
+        let storage_mem = budget.memory_bytes_cost();
+
+        assert!(validated.is_some());
+        assert_eq!(validated, baseline);
+        assert!(
+            tally_cpu < storage_cpu,
+            "{node_count} nodes: cpu {tally_cpu} vs {storage_cpu}"
+        );
+        assert!(
+            tally_mem < storage_mem,
+            "{node_count} nodes: mem {tally_mem} vs {storage_mem}"
+        );
+    }
+}
+
+fn enable_staking(s: &Setup, min_bond: i128, unbonding_period: u32) -> Address {
+    let stake_token =
+        s.e.register_stellar_asset_contract_v2(s.admin.clone())
+            .address();
+    s.oracle.set_bond_config(&BondConfig {
+        token: stake_token.clone(),
+        min_bond,
+        unbonding_period,
+        min_total_stake: 3 * min_bond,
+    });
+    stake_token
+}
+
+fn setup_staked_node<'a>(s: &Setup<'a>, stake_token: &Address, bond: i128) -> Node<'a> {
+    let operator = Address::generate(&s.e);
+    StellarAssetClient::new(&s.e, stake_token).mint(&operator, &bond);
+
+    let receptacle_address = s.oracle.setup_new_node(&operator);
+    let min_bond = s.oracle.get_bond(&operator);
+    if bond > min_bond {
+        s.oracle.bond(&operator, &(bond - min_bond));
+    }
+    Node {
+        operator,
+        receptacle: oracle_receptacle::Client::new(&s.e, &receptacle_address),
+    }
+}
+
+#[test]
+fn test_stake_weighted_consensus() {
+    let s = setup();
+    let stake_token = enable_staking(&s, 100, 10);
+    let whale = setup_staked_node(&s, &stake_token, 600);
+    let small_nodes = [
+        setup_staked_node(&s, &stake_token, 100),
+        setup_staked_node(&s, &stake_token, 100),
+    ];
+    let recipient = Address::generate(&s.e);
+    s.oracle.set_push_enabled(&false);
+    let tx = tx_id(&s.e, 1);
+
+    assert_eq!(s.oracle.get_total_stake(), 800);
+    assert_eq!(s.oracle.get_vote_threshold(), 800);
+
+    s.oracle
+        .set_consensus_policy(&ConsensusPolicy::Fraction(6_667));
+    assert_eq!(s.oracle.get_vote_threshold(), 534);
+
+    // Two of three nodes agreeing is not enough when they hold a minority of the stake.
+    for node in small_nodes.iter() {
+        commit(&s, node, &tx, &recipient, 1_000);
+    }
+    s.oracle.perform_upkeep(&s.keeper);
+    assert!(s.bridge.get_calls().is_empty());
+
+    commit(&s, &whale, &tx, &recipient, 1_000);
+    assert_eq!(s.oracle.perform_upkeep(&s.keeper).processed, 1);
+    assert_eq!(
+        s.bridge.get_calls(),
+        vec![&s.e, inbound_call(&s, &recipient, 1_000)]
+    );
+}
+
+#[test]
+fn test_enabling_staking_keeps_count_votes_until_min_total_stake() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 3);
+    let stake_token = enable_staking(&s, 100, 10);
+    let recipient = Address::generate(&s.e);
+    s.oracle.set_push_enabled(&false);
+
+    // Operators registered before staking hold no bond, so the first bonder must not outvote them.
+    StellarAssetClient::new(&s.e, &stake_token).mint(&nodes[0].operator, &100);
+    s.oracle.bond(&nodes[0].operator, &100);
+    assert_eq!(s.oracle.get_total_stake(), 100);
+    assert_eq!(s.oracle.get_vote_threshold(), 3);
+
+    let tx = tx_id(&s.e, 1);
+    commit(&s, &nodes[0], &tx, &recipient, 1_000);
+    s.oracle.perform_upkeep(&s.keeper);
+    assert!(s.bridge.get_calls().is_empty());
+
+    for node in nodes[1..].iter() {
+        commit(&s, node, &tx, &recipient, 1_000);
+    }
+    assert_eq!(s.oracle.perform_upkeep(&s.keeper).processed, 1);
+
+    // Votes weigh by stake once the minimum total is bonded.
+    for node in nodes[1..].iter() {
+        StellarAssetClient::new(&s.e, &stake_token).mint(&node.operator, &100);
+        s.oracle.bond(&node.operator, &100);
+    }
+    assert_eq!(s.oracle.get_total_stake(), 300);
+    assert_eq!(s.oracle.get_vote_threshold(), 300);
+}
+
+#[test]
+fn test_unbond_and_withdraw() {
+    let s = setup();
+    let stake_token = enable_staking(&s, 100, 10);
+    let node = setup_staked_node(&s, &stake_token, 250);
+    let balance = TokenClient::new(&s.e, &stake_token);
+
+    assert_eq!(balance.balance(&node.operator), 0);
+    assert_eq!(s.oracle.get_bond(&node.operator), 250);
+
+    assert_eq!(
+        s.oracle.try_unbond(&node.operator, &200),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+    assert_eq!(
+        s.oracle.try_unbond(&node.operator, &300),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+
+    let unbonding = s.oracle.unbond(&node.operator, &150);
+    assert_eq!(unbonding.amount, 150);
+    assert_eq!(s.oracle.get_bond(&node.operator), 100);
+    assert_eq!(s.oracle.get_total_stake(), 100);
+
+    assert_eq!(
+        s.oracle.try_withdraw_unbonded(&node.operator),
+        Err(Ok(ContractError::UnbondingNotReady))
+    );
+
+    s.e.ledger().with_mut(|ledger| ledger.sequence_number += 10);
+    assert_eq!(s.oracle.withdraw_unbonded(&node.operator), 150);
+    assert_eq!(balance.balance(&node.operator), 150);
+    assert_eq!(
+        s.oracle.try_withdraw_unbonded(&node.operator),
+        Err(Ok(ContractError::UnbondingNotReady))
+    );
+
+    // Removing the node unbonds whatever stake it has left.
+    s.oracle.remove_node(&node.operator);
+    assert_eq!(s.oracle.get_bond(&node.operator), 0);
+    assert_eq!(s.oracle.get_total_stake(), 0);
+    assert_eq!(s.oracle.get_unbonding(&node.operator).unwrap().amount, 100);
+}
+
+#[test]
+fn test_staking_rejects_invalid_bonds_and_policies() {
+    let s = setup();
+    let operator = Address::generate(&s.e);
+    assert_eq!(
+        s.oracle.try_bond(&operator, &100),
+        Err(Ok(ContractError::StakingNotEnabled))
+    );
+
+    setup_nodes(&s, 1);
+    s.oracle.set_consensus_policy(&ConsensusPolicy::Absolute(1));
+    let stake_token =
+        s.e.register_stellar_asset_contract_v2(s.admin.clone())
+            .address();
+    let config = BondConfig {
+        token: stake_token.clone(),
+        min_bond: 100,
+        unbonding_period: 10,
+        min_total_stake: 300,
+    };
+    assert_eq!(
+        s.oracle.try_set_bond_config(&config),
+        Err(Ok(ContractError::InvalidConsensusPolicy))
+    );
+
+    s.oracle
+        .set_consensus_policy(&ConsensusPolicy::Fraction(6_667));
+    s.oracle.set_bond_config(&config);
+    assert_eq!(
+        s.oracle
+            .try_set_consensus_policy(&ConsensusPolicy::Absolute(1)),
+        Err(Ok(ContractError::InvalidConsensusPolicy))
+    );
+    assert_eq!(
+        s.oracle.try_set_bond_config(&BondConfig {
+            token: s.token.clone(),
+            ..config.clone()
+        }),
+        Err(Ok(ContractError::InvalidBondConfig))
+    );
+    assert_eq!(
+        s.oracle.try_set_bond_config(&BondConfig {
+            min_total_stake: 99,
+            ..config.clone()
+        }),
+        Err(Ok(ContractError::InvalidBondConfig))
+    );
+
+    assert_eq!(
+        s.oracle.try_bond(&operator, &100),
+        Err(Ok(ContractError::NodeNotFound))
+    );
+    let node = setup_staked_node(&s, &stake_token, 100);
+    assert_eq!(
+        s.oracle.try_bond(&node.operator, &0),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_conflicting_attestations_jail_operator() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 3);
+    s.oracle.set_push_enabled(&false);
+    let recipient = Address::generate(&s.e);
+
+    s.oracle.set_consensus_policy(&ConsensusPolicy::Absolute(2));
+    s.oracle.set_slashing_config(&SlashingConfig {
+        slash_bps: 0,
+        max_faults: 2,
+    });
+
+    for id in 1..=2 {
+        let tx = tx_id(&s.e, id);
+        commit(&s, &nodes[0], &tx, &recipient, 1_000);
+        commit(&s, &nodes[1], &tx, &recipient, 1_000);
+        commit(&s, &nodes[2], &tx, &recipient, 9_999);
+        s.oracle.perform_upkeep(&s.keeper);
+
+        assert_eq!(s.oracle.get_faults(&nodes[2].operator), id as u32);
+        assert_eq!(s.oracle.get_faults(&nodes[0].operator), 0);
+    }
+    assert!(s.oracle.get_operator_jailed(&nodes[2].operator));
+    assert_eq!(s.bridge.get_calls().len(), 2);
+
+    // The jailed node no longer votes, so its late attestation cannot be a fault.
+    let tx = tx_id(&s.e, 3);
+    commit(&s, &nodes[0], &tx, &recipient, 1_000);
+    commit(&s, &nodes[1], &tx, &recipient, 1_000);
+    commit(&s, &nodes[2], &tx, &recipient, 9_999);
+    s.oracle.perform_upkeep(&s.keeper);
+
+    assert_eq!(s.bridge.get_calls().len(), 3);
+    assert_eq!(s.oracle.get_faults(&nodes[2].operator), 2);
+
+    s.oracle.unjail_operator(&nodes[2].operator);
+    assert!(!s.oracle.get_operator_jailed(&nodes[2].operator));
+    assert_eq!(s.oracle.get_faults(&nodes[2].operator), 0);
+    assert_eq!(
+        s.oracle.try_unjail_operator(&nodes[2].operator),
+        Err(Ok(ContractError::OperatorNotJailed))
+    );
+}
+
+#[test]
+fn test_conflicting_attestation_slashes_bond() {
+    let s = setup();
+    let stake_token = enable_staking(&s, 100, 10);
+    let nodes = [
+        setup_staked_node(&s, &stake_token, 200),
+        setup_staked_node(&s, &stake_token, 200),
+        setup_staked_node(&s, &stake_token, 200),
+    ];
+    let recipient = Address::generate(&s.e);
+    s.oracle.set_push_enabled(&false);
+    let tx = tx_id(&s.e, 1);
+
+    s.oracle
+        .set_consensus_policy(&ConsensusPolicy::Fraction(6_000));
+    s.oracle.set_slashing_config(&SlashingConfig {
+        slash_bps: 2_500,
+        max_faults: 1,
+    });
+
+    commit(&s, &nodes[0], &tx, &recipient, 1_000);
+    commit(&s, &nodes[1], &tx, &recipient, 1_000);
+    commit(&s, &nodes[2], &tx, &recipient, 9_999);
+    s.oracle.perform_upkeep(&s.keeper);
+
+    // A quarter of the bond is slashed and the rest unbonds as the operator is jailed.
+    assert!(s.oracle.get_operator_jailed(&nodes[2].operator));
+    assert_eq!(s.oracle.get_slashed_stake(), 50);
+    assert_eq!(s.oracle.get_bond(&nodes[2].operator), 0);
+    assert_eq!(
+        s.oracle.get_unbonding(&nodes[2].operator).unwrap().amount,
+        150
+    );
+    assert_eq!(s.oracle.get_total_stake(), 400);
+    assert_eq!(
+        s.oracle.try_bond(&nodes[2].operator, &100),
+        Err(Ok(ContractError::OperatorJailed))
+    );
+
+    s.oracle.withdraw_slashed(&s.admin, &20);
+    assert_eq!(TokenClient::new(&s.e, &stake_token).balance(&s.admin), 20);
+    assert_eq!(s.oracle.get_slashed_stake(), 30);
+
+    // Once a council is in place only a proposal can move slashed stake.
+    let members = setup_council(&s);
+    assert_eq!(
+        s.oracle.try_withdraw_slashed(&s.admin, &30),
+        Err(Ok(ContractError::GovernedByCouncil))
+    );
+    pass_proposal(
+        &s,
+        &members,
+        ProposalAction::WithdrawSlashed(s.admin.clone(), 30),
+    );
+    assert_eq!(TokenClient::new(&s.e, &stake_token).balance(&s.admin), 50);
+    assert_eq!(s.oracle.get_slashed_stake(), 0);
+}
+
+#[test]
+fn test_unbonding_stake_stays_slashable() {
+    let s = setup();
+    let stake_token = enable_staking(&s, 100, 10);
+    let nodes = [
+        setup_staked_node(&s, &stake_token, 200),
+        setup_staked_node(&s, &stake_token, 200),
+        setup_staked_node(&s, &stake_token, 200),
+    ];
+    let recipient = Address::generate(&s.e);
+    s.oracle.set_push_enabled(&false);
+    let tx = tx_id(&s.e, 1);
+
+    s.oracle
+        .set_consensus_policy(&ConsensusPolicy::Fraction(6_000));
+    s.oracle.set_slashing_config(&SlashingConfig {
+        slash_bps: 2_500,
+        max_faults: 1,
+    });
+
+    // Unbonding ahead of a fault does not take the stake out of reach.
+    s.oracle.unbond(&nodes[2].operator, &100);
+    commit(&s, &nodes[0], &tx, &recipient, 1_000);
+    commit(&s, &nodes[1], &tx, &recipient, 1_000);
+    commit(&s, &nodes[2], &tx, &recipient, 9_999);
+    s.oracle.perform_upkeep(&s.keeper);
+
+    // A quarter of both the bond and the unbonding stake is slashed.
+    assert!(s.oracle.get_operator_jailed(&nodes[2].operator));
+    assert_eq!(s.oracle.get_slashed_stake(), 50);
+    assert_eq!(
+        s.oracle.get_unbonding(&nodes[2].operator).unwrap().amount,
+        150
+    );
+    assert_eq!(s.oracle.get_total_stake(), 400);
+
+    s.e.ledger().with_mut(|ledger| ledger.sequence_number += 10);
+    assert_eq!(s.oracle.withdraw_unbonded(&nodes[2].operator), 150);
+    assert_eq!(
+        TokenClient::new(&s.e, &stake_token).balance(&nodes[2].operator),
+        150
+    );
+}
+
+#[test]
+fn test_invalid_slashing_config() {
+    let s = setup();
+    for config in [
+        SlashingConfig {
+            slash_bps: 10_001,
+            max_faults: 1,
+        },
+        SlashingConfig {
+            slash_bps: 1_000,
+            max_faults: 0,
+        },
+    ] {
+        assert_eq!(
+            s.oracle.try_set_slashing_config(&config),
+            Err(Ok(ContractError::InvalidSlashingConfig))
+        );
+    }
+}
+
+#[test]
+fn test_operator_stats() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 3);
+    s.oracle.set_push_enabled(&false);
+    let recipient = Address::generate(&s.e);
+    s.oracle.set_consensus_policy(&ConsensusPolicy::Absolute(2));
+
+    s.e.ledger()
+        .with_mut(|ledger| ledger.sequence_number = 1_000);
+    let tx = tx_id(&s.e, 1);
+    commit(&s, &nodes[0], &tx, &recipient, 1_000);
+    commit(&s, &nodes[1], &tx, &recipient, 1_000);
+    commit(&s, &nodes[2], &tx, &recipient, 9_999);
+    s.oracle.perform_upkeep(&s.keeper);
+
+    s.e.ledger()
+        .with_mut(|ledger| ledger.sequence_number = 2_000);
+    let tx = tx_id(&s.e, 2);
+    commit(&s, &nodes[0], &tx, &recipient, 1_000);
+    commit(&s, &nodes[1], &tx, &recipient, 1_000);
+    s.oracle.perform_upkeep(&s.keeper);
+
+    assert_eq!(
+        s.oracle.get_operator_stats(&nodes[0].operator),
+        OperatorStats {
+            agreed: 2,
+            disagreed: 0,
+            missed: 0,
+            last_active_ledger: 2_000,
+        }
+    );
+    assert_eq!(
+        s.oracle.get_operator_stats(&nodes[2].operator),
+        OperatorStats {
+            agreed: 0,
+            disagreed: 1,
+            missed: 1,
+            last_active_ledger: 1_000,
+        }
+    );
+
+    // A matching vote after upkeep executed the transfer replaces the miss.
+    commit(&s, &nodes[2], &tx, &recipient, 1_000);
+    let stats = s.oracle.get_operator_stats(&nodes[2].operator);
+    assert_eq!((stats.agreed, stats.missed), (1, 0));
+    assert_eq!(
+        s.oracle.try_get_operator_stats(&Address::generate(&s.e)),
+        Err(Ok(ContractError::NodeNotFound))
+    );
+
+    let first_page = s.oracle.get_operator_stats_list(&0, &2);
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(first_page.get_unchecked(1).0, nodes[1].operator);
+
+    let last_page = s.oracle.get_operator_stats_list(&2, &2);
+    assert_eq!(last_page.len(), 1);
+    assert_eq!(last_page.get_unchecked(0).0, nodes[2].operator);
+    assert!(s.oracle.get_operator_stats_list(&3, &2).is_empty());
+}
+
+fn signing_key(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32])
+}
+
+fn register_signing_key(s: &Setup, node: &Node, key: &SigningKey) {
+    let public_key = BytesN::from_array(&s.e, &key.verifying_key().to_bytes());
+    s.oracle.set_operator_key(&node.operator, &public_key);
+}
+
+fn sign(s: &Setup, key: &SigningKey, payload: &Bytes) -> OperatorSignature {
+    let message: std::vec::Vec<u8> = payload.iter().collect();
+    OperatorSignature {
+        public_key: BytesN::from_array(&s.e, &key.verifying_key().to_bytes()),
+        signature: BytesN::from_array(&s.e, &key.sign(&message).to_bytes()),
+    }
+}
+
+#[test]
+fn test_signed_transfer_reaches_threshold() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 3);
+    let keys = [signing_key(1), signing_key(2), signing_key(3)];
+    for (node, key) in nodes.iter().zip(keys.iter()) {
+        register_signing_key(&s, node, key);
+    }
+    s.oracle.set_consensus_policy(&ConsensusPolicy::Absolute(2));
+
+    let recipient = Address::generate(&s.e);
+    let tx = tx_id(&s.e, 1);
+    let sender = String::from_str(&s.e, "0x000000000000000000000000000000000000beef");
+    let payload = encode_evm_payload(&s.e, &recipient, &s.token, 1_000);
+    let signing_payload = s
+        .oracle
+        .get_signing_payload(&tx, &CHAIN_ID, &sender, &payload);
+
+    // A repeated signature from the same operator only counts once.
+    let one_signer = vec![
+        &s.e,
+        sign(&s, &keys[0], &signing_payload),
+        sign(&s, &keys[0], &signing_payload),
+        sign(&s, &signing_key(9), &signing_payload),
+    ];
+    assert_eq!(
+        s.oracle
+            .try_submit_signed_transfer(&tx, &CHAIN_ID, &sender, &payload, &one_signer, &None),
+        Err(Ok(ContractError::QuorumNotReached))
+    );
+
+    let two_signers = vec![
+        &s.e,
+        sign(&s, &keys[0], &signing_payload),
+        sign(&s, &keys[1], &signing_payload),
+    ];
+    s.oracle
+        .submit_signed_transfer(&tx, &CHAIN_ID, &sender, &payload, &two_signers, &None);
+    assert_eq!(
+        s.bridge.get_calls(),
+        vec![&s.e, inbound_call(&s, &recipient, 1_000)]
+    );
+    assert!(s.oracle.get_transfer_processed(&tx));
+    assert_eq!(
+        s.oracle
+            .try_submit_signed_transfer(&tx, &CHAIN_ID, &sender, &payload, &two_signers, &None),
+        Err(Ok(ContractError::TransferAlreadyProcessed))
+    );
+
+    // The receptacle flow sees the transfer as done and only clears it.
+    for node in nodes.iter() {
+        commit(&s, node, &tx, &recipient, 1_000);
+    }
+    s.oracle.perform_upkeep(&s.keeper);
+    assert_eq!(s.bridge.get_calls().len(), 1);
+    for node in nodes.iter() {
+        assert!(pending_transfers(&s, node).is_empty());
+    }
+}
+
+#[test]
+fn test_signed_transfer_rejects_bad_signatures() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 2);
+    let keys = [signing_key(1), signing_key(2)];
+    for (node, key) in nodes.iter().zip(keys.iter()) {
+        register_signing_key(&s, node, key);
+    }
+
+    let public_key = BytesN::from_array(&s.e, &keys[0].verifying_key().to_bytes());
+    assert_eq!(
+        s.oracle
+            .try_set_operator_key(&nodes[1].operator, &public_key),
+        Err(Ok(ContractError::SignerKeyInUse))
+    );
+
+    let recipient = Address::generate(&s.e);
+    let tx = tx_id(&s.e, 1);
+    let sender = String::from_str(&s.e, "0x000000000000000000000000000000000000beef");
+    let payload = encode_evm_payload(&s.e, &recipient, &s.token, 1_000);
+
+    // Signatures over a different amount must not authorize this payload.
+    let other_payload = s.oracle.get_signing_payload(
+        &tx,
+        &CHAIN_ID,
+        &sender,
+        &encode_evm_payload(&s.e, &recipient, &s.token, 9_999),
+    );
+    let signatures = vec![
+        &s.e,
+        sign(&s, &keys[0], &other_payload),
+        sign(&s, &keys[1], &other_payload),
+    ];
+    assert!(s
+        .oracle
+        .try_submit_signed_transfer(&tx, &CHAIN_ID, &sender, &payload, &signatures, &None)
+        .is_err());
+    assert!(s.bridge.get_calls().is_empty());
+}
+
+fn evm_signer(s: &Setup, key: &EvmSigningKey) -> BytesN<20> {
+    let public_key = key.verifying_key().to_encoded_point(false);
+    let hash =
+        s.e.crypto()
+            .keccak256(&Bytes::from_slice(&s.e, &public_key.as_bytes()[1..]))
+            .to_array();
+    BytesN::from_array(&s.e, &hash[12..].try_into().unwrap())
+}
+
+fn sign_evm_payload(
+    s: &Setup,
+    key: &EvmSigningKey,
+    tx_id: &Bytes,
+    sender: &String,
+    payload: &Bytes,
+) -> SourceSignature {
+    let signing_payload = s
+        .oracle
+        .get_source_signing_payload(tx_id, &CHAIN_ID, sender, payload);
+    let digest = s.e.crypto().keccak256(&signing_payload).to_array();
+    let (signature, recovery_id) = key.sign_prehash_recoverable(&digest).unwrap();
+    SourceSignature {
+        signature: BytesN::from_array(&s.e, &signature.to_bytes().into()),
+        recovery_id: 27 + recovery_id.to_byte() as u32,
+    }
+}
+
+#[test]
+fn test_source_signature_verification() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 2);
+    s.oracle.set_push_enabled(&false);
+    let source_key = EvmSigningKey::from_slice(&[7; 32]).unwrap();
+    let other_key = EvmSigningKey::from_slice(&[8; 32]).unwrap();
+
+    let recipient = Address::generate(&s.e);
+    let tx = tx_id(&s.e, 1);
+    let sender = String::from_str(&s.e, "0x000000000000000000000000000000000000beef");
+    let payload = encode_evm_payload(&s.e, &recipient, &s.token, 1_000);
+
+    assert_eq!(
+        s.oracle
+            .try_set_chain_signers(&99, &vec![&s.e, evm_signer(&s, &source_key)]),
+        Err(Ok(ContractError::ChainNotFound))
+    );
+    s.oracle
+        .set_chain_signers(&CHAIN_ID, &vec![&s.e, evm_signer(&s, &source_key)]);
+
+    assert_eq!(
+        s.oracle
+            .try_verify_source_payload(&tx, &CHAIN_ID, &sender, &payload, &None),
+        Err(Ok(ContractError::SourceSignatureRequired))
+    );
+    assert_eq!(
+        s.oracle.try_verify_source_payload(
+            &tx,
+            &CHAIN_ID,
+            &sender,
+            &payload,
+            &Some(sign_evm_payload(&s, &other_key, &tx, &sender, &payload))
+        ),
+        Err(Ok(ContractError::InvalidSourceSignature))
+    );
+
+    // A signature covers a single transfer id.
+    assert_eq!(
+        s.oracle.try_verify_source_payload(
+            &tx_id(&s.e, 2),
+            &CHAIN_ID,
+            &sender,
+            &payload,
+            &Some(sign_evm_payload(&s, &source_key, &tx, &sender, &payload))
+        ),
+        Err(Ok(ContractError::InvalidSourceSignature))
+    );
+
+    // Receptacles refuse unsigned payloads once the chain has signers.
+    assert_eq!(
+        nodes[0]
+            .receptacle
+            .try_commit_transfer(&tx, &CHAIN_ID, &sender, &payload),
+        Err(Err(InvokeError::Contract(
+            ContractError::SourceSignatureRequired as u32
+        )))
+    );
+
+    let signature = sign_evm_payload(&s, &source_key, &tx, &sender, &payload);
+    let source_signature = oracle_receptacle::SourceSignature {
+        signature: signature.signature,
+        recovery_id: signature.recovery_id,
+    };
+    for node in nodes.iter() {
+        node.receptacle.commit_signed_transfer(
+            &tx,
+            &CHAIN_ID,
+            &sender,
+            &payload,
+            &source_signature,
+        );
+    }
+    assert!(s.bridge.get_calls().is_empty());
+    assert_eq!(s.oracle.perform_upkeep(&s.keeper).processed, 1);
+    assert_eq!(
+        s.bridge.get_calls(),
+        vec![&s.e, inbound_call(&s, &recipient, 1_000)]
+    );
+
+    // Clearing the signers makes verification optional again.
+    s.oracle.set_chain_signers(&CHAIN_ID, &Vec::new(&s.e));
+    s.oracle
+        .verify_source_payload(&tx, &CHAIN_ID, &sender, &payload, &None);
+}
+
+#[test]
+fn test_source_signature_out_of_range_is_rejected() {
+    let s = setup();
+    let source_key = EvmSigningKey::from_slice(&[7; 32]).unwrap();
+    s.oracle
+        .set_chain_signers(&CHAIN_ID, &vec![&s.e, evm_signer(&s, &source_key)]);
+
+    let tx = tx_id(&s.e, 1);
+    let sender = String::from_str(&s.e, "0x000000000000000000000000000000000000beef");
+    let payload = encode_evm_payload(&s.e, &Address::generate(&s.e), &s.token, 1_000);
+    let signature = sign_evm_payload(&s, &source_key, &tx, &sender, &payload);
+    let valid = signature.signature.to_array();
+
+    let mut zero_r = valid;
+    zero_r[..32].fill(0);
+    let mut zero_s = valid;
+    zero_s[32..].fill(0);
+    let mut high_s = valid;
+    high_s[32..].fill(0xff);
+    let mut overflow_r = valid;
+    overflow_r[..32].fill(0xff);
+
+    for (bytes, recovery_id) in [
+        (zero_r, signature.recovery_id),
+        (zero_s, signature.recovery_id),
+        (high_s, signature.recovery_id),
+        (overflow_r, signature.recovery_id),
+        (valid, 29),
+    ] {
+        let bad_signature = SourceSignature {
+            signature: BytesN::from_array(&s.e, &bytes),
+            recovery_id,
+        };
+        assert_eq!(
+            s.oracle.try_verify_source_payload(
+                &tx,
+                &CHAIN_ID,
+                &sender,
+                &payload,
+                &Some(bad_signature)
+            ),
+            Err(Ok(ContractError::InvalidSourceSignature))
+        );
+    }
+    s.oracle
+        .verify_source_payload(&tx, &CHAIN_ID, &sender, &payload, &Some(signature));
+}
+
+fn commitment_hash(
+    s: &Setup,
+    tx_id: &Bytes,
+    sender: &String,
+    payload: &Bytes,
+    salt: &BytesN<32>,
+) -> BytesN<32> {
+    let mut preimage = tx_id.clone();
+    preimage.extend_from_array(&CHAIN_ID.to_be_bytes());
+    preimage.append(&sender.clone().to_xdr(&s.e));
+    preimage.append(payload);
+    preimage.extend_from_array(&salt.to_array());
+    s.e.crypto().sha256(&preimage).into()
+}
+
+#[test]
+fn test_commit_reveal_counts_only_timely_commitments() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 3);
+    s.oracle.set_consensus_policy(&ConsensusPolicy::Absolute(2));
+
+    for invalid in [0, DAY_IN_LEDGERS + 1] {
+        assert_eq!(
+            s.oracle.try_set_reveal_delay(&Some(invalid)),
+            Err(Ok(ContractError::InvalidConsensusPolicy))
+        );
+    }
+    s.oracle.set_reveal_delay(&Some(10));
+
+    let recipient = Address::generate(&s.e);
+    let tx = tx_id(&s.e, 1);
+    let sender = String::from_str(&s.e, "0x000000000000000000000000000000000000beef");
+    let payload = encode_evm_payload(&s.e, &recipient, &s.token, 1_000);
+    let salt = BytesN::from_array(&s.e, &[5; 32]);
+    let hash = commitment_hash(&s, &tx, &sender, &payload, &salt);
+
+    s.e.ledger().with_mut(|ledger| ledger.sequence_number = 100);
+    nodes[0].receptacle.commit_hash(&tx, &hash);
+    nodes[1].receptacle.commit_hash(&tx, &hash);
+
+    assert_eq!(
+        nodes[0].receptacle.try_commit_hash(&tx, &hash),
+        Err(Ok(ReceptacleError::AlreadyCommitted))
+    );
+    assert_eq!(
+        nodes[2]
+            .receptacle
+            .try_reveal_transfer(&tx, &CHAIN_ID, &sender, &payload, &salt, &None),
+        Err(Ok(ReceptacleError::NotCommitted))
+    );
+
+    s.e.ledger().with_mut(|ledger| ledger.sequence_number = 105);
+    assert_eq!(
+        nodes[0]
+            .receptacle
+            .try_reveal_transfer(&tx, &CHAIN_ID, &sender, &payload, &salt, &None),
+        Err(Ok(ReceptacleError::RevealWindowNotOpen))
+    );
+
+    s.e.ledger().with_mut(|ledger| ledger.sequence_number = 110);
+    let wrong_salt = BytesN::from_array(&s.e, &[6; 32]);
+    assert_eq!(
+        nodes[0].receptacle.try_reveal_transfer(
+            &tx,
+            &CHAIN_ID,
+            &sender,
+            &payload,
+            &wrong_salt,
+            &None
+        ),
+        Err(Ok(ReceptacleError::RevealMismatch))
+    );
+    nodes[0]
+        .receptacle
+        .reveal_transfer(&tx, &CHAIN_ID, &sender, &payload, &salt, &None);
+
+    // A node that commits after seeing the first reveal is ignored even once it reveals.
+    nodes[2].receptacle.commit_hash(&tx, &hash);
+    s.e.ledger().with_mut(|ledger| ledger.sequence_number = 120);
+    nodes[2]
+        .receptacle
+        .reveal_transfer(&tx, &CHAIN_ID, &sender, &payload, &salt, &None);
+
+    s.oracle.perform_upkeep(&s.keeper);
+    assert!(s.bridge.get_calls().is_empty());
+
+    nodes[1]
+        .receptacle
+        .reveal_transfer(&tx, &CHAIN_ID, &sender, &payload, &salt, &None);
+    s.oracle.perform_upkeep(&s.keeper);
+    assert_eq!(
+        s.bridge.get_calls(),
+        vec![&s.e, inbound_call(&s, &recipient, 1_000)]
+    );
+    assert_eq!(s.oracle.get_operator_stats(&nodes[2].operator).missed, 1);
+    assert!(nodes[0].receptacle.get_commitment(&tx).is_none());
+}
+
+#[test]
+fn test_commit_reveal_ignores_plain_commits_copied_after_reveal() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 3);
+    s.oracle.set_consensus_policy(&ConsensusPolicy::Absolute(2));
+    s.oracle.set_reveal_delay(&Some(10));
+
+    let recipient = Address::generate(&s.e);
+    let tx = tx_id(&s.e, 1);
+    let sender = String::from_str(&s.e, "0x000000000000000000000000000000000000beef");
+    let payload = encode_evm_payload(&s.e, &recipient, &s.token, 1_000);
+    let salt = BytesN::from_array(&s.e, &[5; 32]);
+    let hash = commitment_hash(&s, &tx, &sender, &payload, &salt);
+
+    s.e.ledger().with_mut(|ledger| ledger.sequence_number = 100);
+    nodes[0].receptacle.commit_hash(&tx, &hash);
+    nodes[1].receptacle.commit_hash(&tx, &hash);
+    let junk = BytesN::from_array(&s.e, &[9; 32]);
+    nodes[2].receptacle.commit_hash(&tx, &junk);
+
+    // The lazy node copies the first reveal through a plain commit behind its junk commitment.
+    s.e.ledger().with_mut(|ledger| ledger.sequence_number = 110);
+    nodes[0]
+        .receptacle
+        .reveal_transfer(&tx, &CHAIN_ID, &sender, &payload, &salt, &None);
+    nodes[2]
+        .receptacle
+        .commit_transfer(&tx, &CHAIN_ID, &sender, &payload);
+    assert!(!nodes[2].receptacle.get_commitment(&tx).unwrap().revealed);
+
+    s.oracle.perform_upkeep(&s.keeper);
+    assert!(s.bridge.get_calls().is_empty());
+
+    nodes[1]
+        .receptacle
+        .reveal_transfer(&tx, &CHAIN_ID, &sender, &payload, &salt, &None);
+    s.oracle.perform_upkeep(&s.keeper);
+    assert_eq!(s.bridge.get_calls().len(), 1);
+    assert_eq!(s.oracle.get_operator_stats(&nodes[2].operator).missed, 1);
+}
+
+#[test]
+fn test_commit_reveal_ignores_plain_commits() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 2);
+    let recipient = Address::generate(&s.e);
+    let tx = tx_id(&s.e, 1);
+
+    s.oracle.set_reveal_delay(&Some(10));
+    commit(&s, &nodes[0], &tx, &recipient, 1_000);
+    commit(&s, &nodes[1], &tx, &recipient, 1_000);
+    s.oracle.perform_upkeep(&s.keeper);
+    assert!(s.bridge.get_calls().is_empty());
+
+    s.oracle.set_reveal_delay(&None);
+    s.oracle.perform_upkeep(&s.keeper);
+    assert_eq!(s.bridge.get_calls().len(), 1);
+
+    let sender = String::from_str(&s.e, "0x000000000000000000000000000000000000beef");
+    let payload = encode_evm_payload(&s.e, &recipient, &s.token, 1_000);
+    let salt = BytesN::from_array(&s.e, &[5; 32]);
+    assert_eq!(
+        nodes[0]
+            .receptacle
+            .try_reveal_transfer(&tx, &CHAIN_ID, &sender, &payload, &salt, &None),
+        Err(Ok(ReceptacleError::CommitRevealDisabled))
+    );
+}
+
+#[test]
+fn test_push_attestation_executes_on_threshold_commit() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 3);
+    let recipient = Address::generate(&s.e);
+    let tx = tx_id(&s.e, 1);
+    s.oracle.set_consensus_policy(&ConsensusPolicy::Absolute(2));
+
+    commit(&s, &nodes[0], &tx, &recipient, 1_000);
+    assert!(s.bridge.get_calls().is_empty());
+    assert_eq!(s.oracle.get_pending_votes(&tx).len(), 1);
+
+    // The second commit reaches the threshold and executes without waiting for upkeep.
+    commit(&s, &nodes[1], &tx, &recipient, 1_000);
+    assert_eq!(
+        s.bridge.get_calls(),
+        vec![&s.e, inbound_call(&s, &recipient, 1_000)]
+    );
+    assert!(s.oracle.get_transfer_processed(&tx));
+    assert!(s.oracle.get_pending_votes(&tx).is_empty());
+    // Executing clears the earlier vote from its receptacle too, leaving upkeep nothing to do.
+    assert!(pending_transfers(&s, &nodes[1]).is_empty());
+    assert!(pending_transfers(&s, &nodes[0]).is_empty());
+    assert_eq!(s.oracle.perform_upkeep(&s.keeper).processed, 0);
+    assert_eq!(s.bridge.get_calls().len(), 1);
+
+    // A node that had not voted yet counts as missed until its matching vote arrives.
+    assert_eq!(s.oracle.get_operator_stats(&nodes[2].operator).missed, 1);
+    commit(&s, &nodes[2], &tx, &recipient, 1_000);
+    assert!(pending_transfers(&s, &nodes[2]).is_empty());
+    assert_eq!(s.bridge.get_calls().len(), 1);
+    let stats = s.oracle.get_operator_stats(&nodes[2].operator);
+    assert_eq!((stats.agreed, stats.missed), (1, 0));
+
+    // Repeating the vote earns nothing further.
+    commit(&s, &nodes[2], &tx, &recipient, 1_000);
+    assert_eq!(s.oracle.get_operator_stats(&nodes[2].operator).agreed, 1);
+}
+
+#[test]
+fn test_push_attestation_ignores_jailed_votes() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 3);
+    let recipient = Address::generate(&s.e);
+    s.oracle.set_consensus_policy(&ConsensusPolicy::Absolute(2));
+    s.oracle.set_slashing_config(&SlashingConfig {
+        slash_bps: 0,
+        max_faults: 1,
+    });
+
+    // The node votes on the second transfer before a conflicting vote on the first jails it.
+    let first = tx_id(&s.e, 1);
+    let second = tx_id(&s.e, 2);
+    commit(&s, &nodes[2], &second, &recipient, 9_999);
+    commit(&s, &nodes[2], &first, &recipient, 9_999);
+    commit(&s, &nodes[0], &first, &recipient, 1_000);
+    commit(&s, &nodes[1], &first, &recipient, 1_000);
+    assert!(s.oracle.get_operator_jailed(&nodes[2].operator));
+    let stats = s.oracle.get_operator_stats(&nodes[2].operator);
+
+    commit(&s, &nodes[0], &second, &recipient, 1_000);
+    commit(&s, &nodes[1], &second, &recipient, 1_000);
+    assert!(s.oracle.get_transfer_processed(&second));
+    assert_eq!(s.oracle.get_faults(&nodes[2].operator), 1);
+    assert_eq!(s.oracle.get_operator_stats(&nodes[2].operator), stats);
+}
+
+#[test]
+fn test_push_attestation_ignores_removed_operators() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 3);
+    let recipient = Address::generate(&s.e);
+    let tx = tx_id(&s.e, 1);
+    s.oracle.set_consensus_policy(&ConsensusPolicy::Absolute(2));
+
+    commit(&s, &nodes[2], &tx, &recipient, 1_000);
+    s.oracle.remove_node(&nodes[2].operator);
+
+    commit(&s, &nodes[0], &tx, &recipient, 1_000);
+    assert!(s.bridge.get_calls().is_empty());
+    let pending_votes = s.oracle.get_pending_votes(&tx);
+    assert_eq!(pending_votes.len(), 1);
+    assert!(pending_votes.contains_key(nodes[0].operator.clone()));
+
+    commit(&s, &nodes[1], &tx, &recipient, 1_000);
+    assert_eq!(s.bridge.get_calls().len(), 1);
+}
+
+#[test]
+fn test_push_attestation_requires_known_receptacle() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 1);
+    let tx = tx_id(&s.e, 1);
+    let attestation: Vec<Bytes> = vec![&s.e, tx.clone()];
+
+    assert_eq!(
+        s.oracle
+            .try_push_attestation(&Address::generate(&s.e), &attestation),
+        Err(Ok(ContractError::ReceptacleNotFound))
+    );
+    assert_eq!(
+        s.oracle
+            .try_push_attestation(&nodes[0].receptacle.address, &attestation),
+        Err(Ok(ContractError::MalformedTransferData))
+    );
+}
+
+#[test]
+fn test_perform_upkeep_unions_receptacles_oldest_first() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 3);
+    let recipient = Address::generate(&s.e);
+    s.oracle.set_consensus_policy(&ConsensusPolicy::Absolute(2));
+
+    s.oracle.set_push_enabled(&false);
+
+    // The first node never sees either transfer, and the older one reaches the others at different ledgers.
+    let older = tx_id(&s.e, 2);
+    let newer = tx_id(&s.e, 1);
+    s.e.ledger().with_mut(|ledger| ledger.sequence_number = 100);
+    commit(&s, &nodes[1], &older, &recipient, 2_000);
+
+    s.e.ledger().with_mut(|ledger| ledger.sequence_number = 200);
+    commit(&s, &nodes[1], &newer, &recipient, 1_000);
+    commit(&s, &nodes[2], &newer, &recipient, 1_000);
+
+    s.e.ledger().with_mut(|ledger| ledger.sequence_number = 300);
+    commit(&s, &nodes[2], &older, &recipient, 2_000);
+
+    assert!(pending_transfers(&s, &nodes[0]).is_empty());
+    assert!(s.oracle.get_upkeep_required());
+
+    assert_eq!(s.oracle.perform_upkeep(&s.keeper).processed, 2);
+
+    assert_eq!(
+        s.bridge.get_calls(),
+        vec![
+            &s.e,
+            inbound_call(&s, &recipient, 2_000),
+            inbound_call(&s, &recipient, 1_000),
+        ]
+    );
+    for node in nodes.iter() {
+        assert!(pending_transfers(&s, node).is_empty());
+    }
+    assert!(!s.oracle.get_upkeep_required());
+}
+
+#[test]
+fn test_upkeep_config_limits_batch() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 2);
+    let recipient = Address::generate(&s.e);
+
+    // Pushed votes cannot execute while the route is paused, so the transfers wait for upkeep.
+    s.oracle.set_chain_active(&s.manager, &CHAIN_ID, &false);
+    for id in 1..=4 {
+        for node in nodes.iter() {
+            commit(&s, node, &tx_id(&s.e, id), &recipient, id as i128 * 100);
+        }
+    }
+    s.oracle.set_chain_active(&s.manager, &CHAIN_ID, &true);
+    assert!(s.bridge.get_calls().is_empty());
+
+    // Listing takes a call per node plus a read per listed id, and each transfer a read and a
+    // removal per node, so only the first transfer fits in a budget of fourteen.
+    s.oracle.set_upkeep_config(&UpkeepConfig {
+        max_transfers: 5,
+        max_receptacle_reads: 14,
+    });
+    assert_eq!(
+        s.oracle.perform_upkeep(&s.keeper),
+        UpkeepSummary {
+            processed: 1,
+            skipped: 0,
+            failed: 0,
+            remaining: 3,
+            reward: 0,
+        }
+    );
+
+    s.oracle.set_upkeep_config(&UpkeepConfig {
+        max_transfers: 2,
+        max_receptacle_reads: 100,
+    });
+    assert_eq!(
+        s.oracle.perform_upkeep(&s.keeper),
+        UpkeepSummary {
+            processed: 2,
+            skipped: 0,
+            failed: 0,
+            remaining: 1,
+            reward: 0,
+        }
+    );
+    assert_eq!(s.bridge.get_calls().len(), 3);
+
+    for config in [
+        UpkeepConfig {
+            max_transfers: 0,
+            max_receptacle_reads: 10,
+        },
+        UpkeepConfig {
+            max_transfers: 5,
+            max_receptacle_reads: 0,
+        },
+    ] {
+        assert_eq!(
+            s.oracle.try_set_upkeep_config(&config),
+            Err(Ok(ContractError::InvalidUpkeepConfig))
+        );
+    }
+    assert_eq!(s.oracle.get_upkeep_config().max_transfers, 2);
+}
+
+#[test]
+fn test_perform_upkeep_for_targets_transfers() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 3);
+    let recipient = Address::generate(&s.e);
+    s.oracle.set_consensus_policy(&ConsensusPolicy::Absolute(2));
+    s.oracle.set_upkeep_config(&UpkeepConfig {
+        max_transfers: 1,
+        max_receptacle_reads: 100,
+    });
+
+    s.oracle.set_push_enabled(&false);
+    let stuck = tx_id(&s.e, 1);
+    s.e.ledger().with_mut(|ledger| ledger.sequence_number = 100);
+    commit(&s, &nodes[0], &stuck, &recipient, 1_000);
+
+    let tx = tx_id(&s.e, 2);
+    s.e.ledger().with_mut(|ledger| ledger.sequence_number = 200);
+    commit(&s, &nodes[0], &tx, &recipient, 2_000);
+    commit(&s, &nodes[1], &tx, &recipient, 2_000);
+
+    s.oracle.set_chain_active(&s.manager, &CHAIN_ID, &false);
+    assert_eq!(
+        s.oracle
+            .perform_upkeep_for(&s.keeper, &vec![&s.e, tx.clone()]),
+        vec![&s.e, (tx.clone(), UpkeepOutcome::RouteInactive)]
+    );
+    s.oracle.set_chain_active(&s.manager, &CHAIN_ID, &true);
+
+    // The stuck transfer at the head does not use up the single slot.
+    assert_eq!(
+        nodes[0].receptacle.get_pending_transfers(&1, &5),
+        vec![&s.e, (tx.clone(), 200)]
+    );
+    assert_eq!(
+        s.oracle.perform_upkeep(&s.keeper),
+        UpkeepSummary {
+            processed: 1,
+            skipped: 1,
+            failed: 0,
+            remaining: 0,
+            reward: 0,
+        }
+    );
+    assert_eq!(
+        s.bridge.get_calls(),
+        vec![&s.e, inbound_call(&s, &recipient, 2_000)]
+    );
+
+    let unknown = tx_id(&s.e, 3);
+    assert_eq!(
+        s.oracle.perform_upkeep_for(
+            &s.keeper,
+            &vec![&s.e, stuck.clone(), tx.clone(), unknown.clone()]
+        ),
+        vec![
+            &s.e,
+            (stuck.clone(), UpkeepOutcome::NoConsensus),
+            (tx.clone(), UpkeepOutcome::AlreadyProcessed),
+            (unknown, UpkeepOutcome::NotFound),
+        ]
+    );
+    assert_eq!(pending_transfers(&s, &nodes[0]), vec![&s.e, stuck.clone()]);
+
+    // A manager can drop an id that will never execute.
+    assert_eq!(
+        s.oracle
+            .try_purge_transfer(&Address::generate(&s.e), &stuck),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    s.oracle.purge_transfer(&s.manager, &stuck);
+    assert!(pending_transfers(&s, &nodes[0]).is_empty());
+
+    // Under a council purging takes a proposal.
+    let other = tx_id(&s.e, 4);
+    commit(&s, &nodes[1], &other, &recipient, 4_000);
+    let members = setup_council(&s);
+    assert_eq!(
+        s.oracle.try_purge_transfer(&s.manager, &other),
+        Err(Ok(ContractError::GovernedByCouncil))
+    );
+    pass_proposal(&s, &members, ProposalAction::PurgeTransfer(other));
+    assert!(pending_transfers(&s, &nodes[1]).is_empty());
+    assert!(!s.oracle.get_upkeep_required());
+}
+
+#[test]
+fn test_check_upkeep_reports_ready_transfers() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 3);
+    let recipient = Address::generate(&s.e);
+    s.oracle.set_consensus_policy(&ConsensusPolicy::Absolute(2));
+
+    // Nothing can execute while the route is paused, pushed votes included.
+    s.oracle.set_chain_active(&s.manager, &CHAIN_ID, &false);
+    let ready = tx_id(&s.e, 1);
+    commit(&s, &nodes[0], &ready, &recipient, 1_000);
+    commit(&s, &nodes[1], &ready, &recipient, 1_000);
+    let single = tx_id(&s.e, 2);
+    commit(&s, &nodes[0], &single, &recipient, 2_000);
+    let split = tx_id(&s.e, 3);
+    commit(&s, &nodes[0], &split, &recipient, 3_000);
+    commit(&s, &nodes[1], &split, &recipient, 3_333);
+
+    assert!(s.bridge.get_calls().is_empty());
+    assert!(!s.oracle.check_upkeep().upkeep_needed);
+    s.oracle.set_chain_active(&s.manager, &CHAIN_ID, &true);
+
+    let check = s.oracle.check_upkeep();
+    assert!(check.upkeep_needed);
+    assert_eq!(check.perform_data, vec![&s.e, ready.clone()]);
+    assert_eq!(check.vote_threshold, 2);
+    assert_eq!(
+        check.transfers,
+        vec![
+            &s.e,
+            TransferVotes {
+                tx_id: ready.clone(),
+                votes: 2,
+                leading_weight: 2,
+                last_failure: None,
+            },
+            TransferVotes {
+                tx_id: single.clone(),
+                votes: 1,
+                leading_weight: 1,
+                last_failure: None,
+            },
+            TransferVotes {
+                tx_id: split,
+                votes: 2,
+                leading_weight: 1,
+                last_failure: None,
+            },
+        ]
+    );
+
+    assert_eq!(
+        s.oracle.perform_upkeep_for(&s.keeper, &check.perform_data),
+        vec![&s.e, (ready, UpkeepOutcome::Executed)]
+    );
+    let check = s.oracle.check_upkeep();
+    assert!(!check.upkeep_needed);
+    assert_eq!(check.transfers.len(), 2);
+
+    // A pushed vote that executes a transfer takes it out of the check as well.
+    commit(&s, &nodes[1], &single, &recipient, 2_000);
+    assert_eq!(s.bridge.get_calls().len(), 2);
+    let check = s.oracle.check_upkeep();
+    assert!(!check.upkeep_needed);
+    assert_eq!(check.transfers.len(), 1);
+}
+
+#[test]
+fn test_keeper_rewards() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 2);
+    let recipient = Address::generate(&s.e);
+    let reward_token =
+        s.e.register_stellar_asset_contract_v2(s.admin.clone())
+            .address();
+    StellarAssetClient::new(&s.e, &reward_token).mint(&s.admin, &250);
+
+    assert_eq!(
+        s.oracle.try_fund_reward_pool(&250),
+        Err(Ok(ContractError::KeeperRewardsNotEnabled))
+    );
+    let config = KeeperRewardConfig {
+        token: reward_token.clone(),
+        reward_per_transfer: 100,
+        max_reward_per_call: 150,
+    };
+    for invalid in [
+        KeeperRewardConfig {
+            reward_per_transfer: 0,
+            ..config.clone()
+        },
+        KeeperRewardConfig {
+            max_reward_per_call: 99,
+            ..config.clone()
+        },
+    ] {
+        assert_eq!(
+            s.oracle.try_set_keeper_reward_config(&invalid),
+            Err(Ok(ContractError::InvalidKeeperRewardConfig))
+        );
+    }
+    s.oracle.set_keeper_reward_config(&config);
+    assert_eq!(s.oracle.fund_reward_pool(&250), 250);
+
+    s.oracle.set_push_enabled(&false);
+    for id in 1..=3 {
+        for node in nodes.iter() {
+            commit(&s, node, &tx_id(&s.e, id), &recipient, id as i128 * 100);
+        }
+    }
+
+    // Two transfers would earn 200, but a single call is capped at 150.
+    s.oracle.set_upkeep_config(&UpkeepConfig {
+        max_transfers: 2,
+        max_receptacle_reads: 100,
+    });
+    assert_eq!(s.oracle.perform_upkeep(&s.keeper).reward, 150);
+    let reward_client = TokenClient::new(&s.e, &reward_token);
+    assert_eq!(reward_client.balance(&s.keeper), 150);
+    assert_eq!(s.oracle.get_reward_pool(), 100);
+
+    // The pool pays out what it has left.
+    s.oracle.withdraw_reward_pool(&s.admin, &40);
+    let keeper = Address::generate(&s.e);
+    s.oracle
+        .perform_upkeep_for(&keeper, &vec![&s.e, tx_id(&s.e, 3)]);
+    assert_eq!(reward_client.balance(&keeper), 60);
+    assert_eq!(s.oracle.get_reward_pool(), 0);
+    assert_eq!(reward_client.balance(&s.admin), 40);
+
+    assert_eq!(
+        s.oracle.try_withdraw_reward_pool(&s.admin, &1),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+
+    // Once a council is in place the pool only moves through proposals.
+    let members = setup_council(&s);
+    let funder = Address::generate(&s.e);
+    StellarAssetClient::new(&s.e, &reward_token).mint(&funder, &80);
+    assert_eq!(
+        s.oracle.try_fund_reward_pool(&80),
+        Err(Ok(ContractError::GovernedByCouncil))
+    );
+    assert_eq!(
+        s.oracle.try_withdraw_reward_pool(&s.admin, &80),
+        Err(Ok(ContractError::GovernedByCouncil))
+    );
+    pass_proposal(
+        &s,
+        &members,
+        ProposalAction::FundRewardPool(funder.clone(), 80),
+    );
+    assert_eq!(s.oracle.get_reward_pool(), 80);
+    assert_eq!(reward_client.balance(&funder), 0);
+    pass_proposal(
+        &s,
+        &members,
+        ProposalAction::WithdrawRewardPool(s.admin.clone(), 30),
+    );
+    assert_eq!(s.oracle.get_reward_pool(), 50);
+    assert_eq!(reward_client.balance(&s.admin), 70);
+}
+
+#[test]
+fn test_upgrade_delay_changes() {
+    let s = setup();
+    assert_eq!(s.oracle.get_upgrade_delay(), 2 * DAY_IN_LEDGERS);
+
+    for delay in [0, DAY_IN_LEDGERS - 1] {
+        assert_eq!(
+            s.oracle.try_set_upgrade_delay(&delay),
+            Err(Ok(ContractError::InvalidUpgradeDelay))
+        );
+    }
+
+    // Raising the delay applies at once.
+    s.oracle.set_upgrade_delay(&(3 * DAY_IN_LEDGERS));
+    assert_eq!(s.oracle.get_upgrade_delay(), 3 * DAY_IN_LEDGERS);
+    assert!(s.oracle.get_pending_upgrade_delay().is_none());
+
+    // Lowering it waits out the current delay.
+    s.e.ledger().with_mut(|ledger| ledger.sequence_number = 100);
+    s.oracle.set_upgrade_delay(&DAY_IN_LEDGERS);
+    assert_eq!(s.oracle.get_upgrade_delay(), 3 * DAY_IN_LEDGERS);
+    assert_eq!(
+        s.oracle.get_pending_upgrade_delay(),
+        Some(PendingUpgradeDelay {
+            delay: DAY_IN_LEDGERS,
+            effective_ledger: 100 + 3 * DAY_IN_LEDGERS,
+        })
+    );
+
+    s.oracle
+        .announce_upgrade(&BytesN::from_array(&s.e, &[1; 32]));
+    assert_eq!(
+        s.oracle.get_pending_upgrade().unwrap().executable_ledger,
+        100 + 3 * DAY_IN_LEDGERS
+    );
+
+    s.e.as_contract(&s.oracle.address, || {
+        s.e.storage()
+            .instance()
+            .extend_ttl(4 * DAY_IN_LEDGERS, 4 * DAY_IN_LEDGERS)
+    });
+    s.e.ledger()
+        .with_mut(|ledger| ledger.sequence_number = 100 + 3 * DAY_IN_LEDGERS - 1);
+    assert_eq!(s.oracle.get_upgrade_delay(), 3 * DAY_IN_LEDGERS);
+
+    s.e.ledger()
+        .with_mut(|ledger| ledger.sequence_number = 100 + 3 * DAY_IN_LEDGERS);
+    assert_eq!(s.oracle.get_upgrade_delay(), DAY_IN_LEDGERS);
+    assert!(s.oracle.get_pending_upgrade_delay().is_none());
+}
+
+fn setup_council(s: &Setup) -> std::vec::Vec<Address> {
+    let members = std::vec![
+        Address::generate(&s.e),
+        Address::generate(&s.e),
+        Address::generate(&s.e),
+    ];
+    s.oracle.setup_council(
+        &vec![
+            &s.e,
+            members[0].clone(),
+            members[1].clone(),
+            members[2].clone(),
+        ],
+        &2,
+    );
+    members
+}
+
+fn pass_proposal(s: &Setup, members: &[Address], action: ProposalAction) {
+    let proposal_id = s.oracle.create_proposal(&members[0], &action);
+    s.oracle.approve_proposal(&members[1], &proposal_id);
+    s.oracle.execute_proposal(&proposal_id);
+}
+
+#[test]
+fn test_council_governs_bridge_chain_and_guardian() {
+    let s = setup();
+    let members = setup_council(&s);
+    let guardian = Address::generate(&s.e);
+
+    assert_eq!(
+        s.oracle.try_setup_bridge(&Address::generate(&s.e)),
+        Err(Ok(ContractError::GovernedByCouncil))
+    );
+    assert_eq!(
+        s.oracle.try_remove_chain(&s.manager, &CHAIN_ID),
+        Err(Ok(ContractError::GovernedByCouncil))
+    );
+    assert_eq!(
+        s.oracle.try_set_guardian(&guardian),
+        Err(Ok(ContractError::GovernedByCouncil))
+    );
+
+    pass_proposal(&s, &members, ProposalAction::SetGuardian(guardian.clone()));
+    assert_eq!(s.oracle.get_guardian(), Some(guardian.clone()));
+
+    let bridge = MockBridgeClient::new(&s.e, &s.e.register(MockBridge, ()));
+    pass_proposal(
+        &s,
+        &members,
+        ProposalAction::SetupBridge(bridge.address.clone()),
+    );
+    let recipient = String::from_str(&s.e, "0x000000000000000000000000000000000000dead");
+    s.oracle
+        .initiate_rebalancing(&guardian, &CHAIN_ID, &recipient, &s.token, &500);
+    assert!(s.bridge.get_calls().is_empty());
+    assert_eq!(bridge.get_calls().len(), 1);
+
+    pass_proposal(&s, &members, ProposalAction::RemoveChain(CHAIN_ID));
+    assert!(s.oracle.get_all_supported_chains().is_empty());
+}
+
+#[test]
+fn test_council_proposal_lifecycle() {
+    let s = setup();
+    let member = Address::generate(&s.e);
+    assert_eq!(
+        s.oracle
+            .try_setup_council(&vec![&s.e, member.clone(), member.clone()], &1),
+        Err(Ok(ContractError::DuplicateCouncilMember))
+    );
+    for quorum in [0, 2] {
+        assert_eq!(
+            s.oracle
+                .try_setup_council(&vec![&s.e, member.clone()], &quorum),
+            Err(Ok(ContractError::InvalidQuorum))
+        );
+    }
+
+    let members = setup_council(&s);
+    assert_eq!(s.oracle.get_council().len(), 3);
+    assert_eq!(s.oracle.get_council_quorum(), 2);
+    assert_eq!(
+        s.oracle.try_setup_council(&vec![&s.e, member], &1),
+        Err(Ok(ContractError::CouncilAlreadySet))
+    );
+
+    let operator = Address::generate(&s.e);
+    assert_eq!(
+        s.oracle.try_setup_new_node(&operator),
+        Err(Ok(ContractError::GovernedByCouncil))
+    );
+    assert_eq!(
+        s.oracle.try_create_proposal(
+            &Address::generate(&s.e),
+            &ProposalAction::SetupNewNode(operator.clone())
+        ),
+        Err(Ok(ContractError::NotCouncilMember))
+    );
+    assert_eq!(
+        s.oracle.try_get_proposal(&0),
+        Err(Ok(ContractError::ProposalNotFound))
+    );
+
+    let proposal_id = s
+        .oracle
+        .create_proposal(&members[0], &ProposalAction::SetupNewNode(operator.clone()));
+    assert_eq!(
+        s.oracle.try_approve_proposal(&members[0], &proposal_id),
+        Err(Ok(ContractError::AlreadyApproved))
+    );
+    assert_eq!(
+        s.oracle.try_execute_proposal(&proposal_id),
+        Err(Ok(ContractError::QuorumNotReached))
+    );
+
+    s.oracle.approve_proposal(&members[1], &proposal_id);
+    s.oracle.execute_proposal(&proposal_id);
+    assert!(s.oracle.get_proposal(&proposal_id).executed);
+    assert!(s.oracle.try_get_receptacle_address(&operator).is_ok());
+
+    assert_eq!(
+        s.oracle.try_execute_proposal(&proposal_id),
+        Err(Ok(ContractError::ProposalAlreadyExecuted))
+    );
+    assert_eq!(
+        s.oracle.try_approve_proposal(&members[2], &proposal_id),
+        Err(Ok(ContractError::ProposalAlreadyExecuted))
+    );
+}
+
+#[test]
+fn test_council_proposal_expires() {
+    let s = setup();
+    let members = setup_council(&s);
+    let proposal_id = s
+        .oracle
+        .create_proposal(&members[0], &ProposalAction::SetConsensusQuorum(1));
+
+    s.e.as_contract(&s.oracle.address, || {
+        s.e.storage()
+            .instance()
+            .extend_ttl(2 * PROPOSAL_LIFETIME, 2 * PROPOSAL_LIFETIME)
+    });
+    let expiration_ledger = s.oracle.get_proposal(&proposal_id).expiration_ledger;
+    s.e.ledger()
+        .with_mut(|ledger| ledger.sequence_number = expiration_ledger + 1);
+
+    assert_eq!(
+        s.oracle.try_approve_proposal(&members[1], &proposal_id),
+        Err(Ok(ContractError::ProposalExpired))
+    );
+    assert_eq!(
+        s.oracle.try_execute_proposal(&proposal_id),
+        Err(Ok(ContractError::ProposalExpired))
+    );
+}
+
+#[test]
+fn test_upgrade_waits_for_delay() {
+    let s = setup();
+    assert_eq!(
+        s.oracle.try_cancel_upgrade(),
+        Err(Ok(ContractError::NoPendingUpgrade))
+    );
+    assert_eq!(
+        s.oracle.try_execute_upgrade(),
+        Err(Ok(ContractError::NoPendingUpgrade))
+    );
+
+    s.oracle
+        .announce_upgrade(&BytesN::from_array(&s.e, &[1; 32]));
+    assert_eq!(
+        s.oracle
+            .try_announce_upgrade(&BytesN::from_array(&s.e, &[2; 32])),
+        Err(Ok(ContractError::UpgradeAlreadyPending))
+    );
+    s.oracle.cancel_upgrade();
+    assert!(s.oracle.get_pending_upgrade().is_none());
+
+    // Keep the uploaded code live until the delay has passed.
+    s.e.ledger()
+        .with_mut(|ledger| ledger.min_persistent_entry_ttl = 3 * DAY_IN_LEDGERS);
+    let wasm_hash = s.e.deployer().upload_contract_wasm(oracle_receptacle::WASM);
+    s.oracle.announce_upgrade(&wasm_hash);
+    let executable_ledger = s.oracle.get_pending_upgrade().unwrap().executable_ledger;
+    assert_eq!(executable_ledger, 2 * DAY_IN_LEDGERS);
+
+    s.e.as_contract(&s.oracle.address, || {
+        s.e.storage()
+            .instance()
+            .extend_ttl(3 * DAY_IN_LEDGERS, 3 * DAY_IN_LEDGERS)
+    });
+    s.e.ledger()
+        .with_mut(|ledger| ledger.sequence_number = executable_ledger - 1);
+    assert_eq!(
+        s.oracle.try_execute_upgrade(),
+        Err(Ok(ContractError::UpgradeNotReady))
+    );
+
+    s.e.ledger()
+        .with_mut(|ledger| ledger.sequence_number = executable_ledger);
+    s.oracle.execute_upgrade();
+
+    // The oracle address now runs the receptacle code.
+    let upgraded = oracle_receptacle::Client::new(&s.e, &s.oracle.address);
+    assert!(upgraded.get_pending_transfers(&0, &10).is_empty());
+}
+
+#[test]
+fn test_guardian_pauses_and_admin_unpauses() {
+    let s = setup();
+    let user = Address::generate(&s.e);
+    let recipient = String::from_str(&s.e, "0x000000000000000000000000000000000000dead");
+    assert_eq!(
+        s.oracle.try_pause(),
+        Err(Ok(ContractError::GuardianNotFound))
+    );
+
+    let guardian = Address::generate(&s.e);
+    s.oracle.set_guardian(&guardian);
+    s.oracle.pause();
+    assert_eq!(s.e.auths()[0].0, guardian);
+    assert!(s.oracle.get_pause_state());
+
+    assert_eq!(
+        s.oracle
+            .try_initiate_outgoing_transfer(&user, &CHAIN_ID, &recipient, &s.token, &1_000),
+        Err(Ok(ContractError::Paused))
+    );
+    assert_eq!(
+        s.oracle.try_perform_upkeep(&s.keeper),
+        Err(Ok(ContractError::Paused))
+    );
+
+    s.oracle.unpause();
+    assert_eq!(s.e.auths()[0].0, s.admin);
+    assert!(!s.oracle.get_pause_state());
+    s.oracle
+        .initiate_outgoing_transfer(&user, &CHAIN_ID, &recipient, &s.token, &1_000);
+    assert_eq!(s.bridge.get_calls().len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_guardian_cannot_unpause() {
+    let s = setup();
+    let guardian = Address::generate(&s.e);
+    s.oracle.set_guardian(&guardian);
+    s.oracle.pause();
+
+    s.e.set_auths(&[]);
+    s.oracle
+        .mock_auths(&[MockAuth {
+            address: &guardian,
+            invoke: &MockAuthInvoke {
+                contract: &s.oracle.address,
+                fn_name: "unpause",
+                args: ().into_val(&s.e),
+                sub_invokes: &[],
+            },
+        }])
+        .unpause();
+}
+
+#[test]
+fn test_paused_routes_reject_outgoing_transfers() {
+    let s = setup();
+    let user = Address::generate(&s.e);
+    let recipient = String::from_str(&s.e, "0x000000000000000000000000000000000000dead");
+
+    s.oracle.set_chain_active(&s.manager, &CHAIN_ID, &false);
+    assert!(!s.oracle.get_chain_active(&CHAIN_ID));
+    assert_eq!(
+        s.oracle
+            .try_initiate_outgoing_transfer(&user, &CHAIN_ID, &recipient, &s.token, &1_000),
+        Err(Ok(ContractError::ChainPaused))
+    );
+    s.oracle.set_chain_active(&s.manager, &CHAIN_ID, &true);
+
+    s.oracle
+        .set_token_chain_active(&s.manager, &s.token, &CHAIN_ID, &false);
+    assert!(!s.oracle.get_token_chain_active(&s.token, &CHAIN_ID));
+    assert_eq!(
+        s.oracle
+            .try_initiate_outgoing_transfer(&user, &CHAIN_ID, &recipient, &s.token, &1_000),
+        Err(Ok(ContractError::TokenChainPaused))
+    );
+    assert!(s.bridge.get_calls().is_empty());
+
+    s.oracle
+        .set_token_chain_active(&s.manager, &s.token, &CHAIN_ID, &true);
+    assert!(s.oracle.get_token_chain_active(&s.token, &CHAIN_ID));
+    s.oracle
+        .initiate_outgoing_transfer(&user, &CHAIN_ID, &recipient, &s.token, &1_000);
+    assert_eq!(s.bridge.get_calls().len(), 1);
+
+    assert_eq!(
+        s.oracle
+            .try_set_chain_active(&Address::generate(&s.e), &CHAIN_ID, &false),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(
+        s.oracle.try_set_chain_active(&s.manager, &99, &false),
+        Err(Ok(ContractError::ChainNotFound))
+    );
+}
+
+#[test]
+fn test_keeper_reward_skips_cleared_transfers() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 2);
+    let recipient = Address::generate(&s.e);
+    let reward_token =
+        s.e.register_stellar_asset_contract_v2(s.admin.clone())
+            .address();
+    StellarAssetClient::new(&s.e, &reward_token).mint(&s.admin, &100);
+    s.oracle.set_keeper_reward_config(&KeeperRewardConfig {
+        token: reward_token,
+        reward_per_transfer: 100,
+        max_reward_per_call: 100,
+    });
+    s.oracle.fund_reward_pool(&100);
+
+    // Signed votes execute the transfer while the first receptacle still queues it.
+    let tx = tx_id(&s.e, 1);
+    s.oracle.set_push_enabled(&false);
+    commit(&s, &nodes[0], &tx, &recipient, 1_000);
+    let keys = [signing_key(1), signing_key(2)];
+    for (node, key) in nodes.iter().zip(keys.iter()) {
+        register_signing_key(&s, node, key);
+    }
+    let sender = String::from_str(&s.e, "0x000000000000000000000000000000000000beef");
+    let payload = encode_evm_payload(&s.e, &recipient, &s.token, 1_000);
+    let signing_payload = s
+        .oracle
+        .get_signing_payload(&tx, &CHAIN_ID, &sender, &payload);
+    let signatures = vec![
+        &s.e,
+        sign(&s, &keys[0], &signing_payload),
+        sign(&s, &keys[1], &signing_payload),
+    ];
+    s.oracle
+        .submit_signed_transfer(&tx, &CHAIN_ID, &sender, &payload, &signatures, &None);
+    assert_eq!(s.bridge.get_calls().len(), 1);
+    assert_eq!(pending_transfers(&s, &nodes[0]).len(), 1);
+
+    let summary = s.oracle.perform_upkeep(&s.keeper);
+    assert_eq!(summary.processed, 1);
+    assert_eq!(summary.reward, 0);
+    assert!(pending_transfers(&s, &nodes[0]).is_empty());
+    assert_eq!(s.oracle.get_reward_pool(), 100);
+}
+
+#[test]
+fn test_signed_transfer_rejects_malformed_payloads() {
+    let s = setup();
+    let nodes = setup_nodes(&s, 1);
+    let source_key = EvmSigningKey::from_slice(&[7; 32]).unwrap();
+    s.oracle
+        .set_chain_signers(&CHAIN_ID, &vec![&s.e, evm_signer(&s, &source_key)]);
+
+    let tx = tx_id(&s.e, 1);
+    let sender = String::from_str(&s.e, "0x000000000000000000000000000000000000beef");
+    let valid = encode_evm_payload(&s.e, &Address::generate(&s.e), &s.token, 1_000);
+    let mut payload = [0u8; 320];
+    valid.copy_into_slice(&mut payload);
+
+    let mut short_key = payload;
+    short_key[159] = 55;
+    let mut bad_checksum = payload;
+    bad_checksum[215] = if bad_checksum[215] == b'A' {
+        b'B'
+    } else {
+        b'A'
+    };
+    let mut bad_character = payload;
+    bad_character[256] = b'1';
+    let mut oversized_amount = payload;
+    oversized_amount[100] = 1;
+    let mut negative_amount = payload;
+    negative_amount[112] = 0x80;
+
+    for malformed in [
+        Bytes::from_slice(&s.e, &payload[..311]),
+        Bytes::from_array(&s.e, &short_key),
+        Bytes::from_array(&s.e, &bad_checksum),
+        Bytes::from_array(&s.e, &bad_character),
+        Bytes::from_array(&s.e, &oversized_amount),
+        Bytes::from_array(&s.e, &negative_amount),
+    ] {
+        assert_eq!(
+            s.oracle
+                .try_get_signing_payload(&tx, &CHAIN_ID, &sender, &malformed),
+            Err(Ok(ContractError::MalformedTransferData))
+        );
+        // Decoding runs before the missing source signature is noticed.
+        assert_eq!(
+            s.oracle.try_submit_signed_transfer(
+                &tx,
+                &CHAIN_ID,
+                &sender,
+                &malformed,
+                &Vec::new(&s.e),
+                &None
+            ),
+            Err(Ok(ContractError::MalformedTransferData))
+        );
+        // Receptacles decode the same way instead of trapping on the payload.
+        assert_eq!(
+            nodes[0]
+                .receptacle
+                .try_commit_transfer(&tx, &CHAIN_ID, &sender, &malformed),
+            Err(Ok(ReceptacleError::MalformedTransferData))
+        );
+    }
+    assert!(s
+        .oracle
+        .try_get_signing_payload(&tx, &CHAIN_ID, &sender, &valid)
+        .is_ok());
+
+    // Account strkeys decode as well as contract ones.
+    let account = Address::from_str(
+        &s.e,
+        "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+    );
+    let account_payload = encode_evm_payload(&s.e, &account, &s.token, 1_000);
+    assert!(s
+        .oracle
+        .try_get_signing_payload(&tx, &CHAIN_ID, &sender, &account_payload)
+        .is_ok());
+}
@@ -1,6 +1,9 @@
 use soroban_sdk::{Address, Env, String, Vec};
 
-use crate::{chains::read_chain, data::DataKey, types::DestinationChainDetails};
+use crate::{
+    chains::read_chain, data::DataKey, error::ContractError, pause::write_token_chain_paused,
+    types::DestinationChainDetails,
+};
 
 // ReceptacleId(Address),
 // IsOperator(Address),
@@ -17,13 +20,18 @@ pub fn read_token_chain_is_supported(e: &Env, token_id: Address, chain_id: u32)
     e.storage().instance().has(&key)
 }
 
-pub fn write_token_chain_map(e: &Env, token_id: Address, chain_id: u32, destination_token: String) {
+pub fn write_token_chain_map(
+    e: &Env,
+    token_id: Address,
+    chain_id: u32,
+    destination_token: String,
+) -> Result<(), ContractError> {
     let key = DataKey::DestinationChainToken(token_id.clone(), chain_id);
-    let chain_details = read_chain(e, chain_id).unwrap();
+    let chain_details = read_chain(e, chain_id)?;
     let destination_token_details = DestinationChainDetails {
         chain_name: chain_details.chain_name,
-        chain_id: chain_id,
-        destination_token: destination_token,
+        chain_id,
+        destination_token,
     };
 
     if !read_token_chain_is_supported(e, token_id.clone(), chain_id) {
@@ -35,14 +43,17 @@ pub fn write_token_chain_map(e: &Env, token_id: Address, chain_id: u32, destinat
     }
 
     e.storage().instance().set(&key, &destination_token_details);
+
+    Ok(())
 }
 
 pub fn delete_token_chain_map(e: &Env, token_id: Address, chain_id: u32) {
     let key = DataKey::DestinationChainToken(token_id.clone(), chain_id);
     e.storage().instance().remove(&key);
+    write_token_chain_paused(e, token_id.clone(), chain_id, false);
 
     let chain_list: Vec<u32> = read_token_supported_chain_list(e, token_id.clone());
-    let mut updated_list: Vec<u32> = Vec::new(&e);
+    let mut updated_list: Vec<u32> = Vec::new(e);
 
     for i in 0..chain_list.len() {
         if chain_list.get_unchecked(i) != chain_id {
@@ -59,26 +70,25 @@ pub fn read_destination_chain_token(
     e: &Env,
     token_id: Address,
     chain_id: u32,
-) -> Option<DestinationChainDetails> {
+) -> Result<DestinationChainDetails, ContractError> {
     let key = DataKey::DestinationChainToken(token_id, chain_id);
     e.storage()
         .instance()
         .get(&key)
-        .expect("Destination token details not found!")
+        .ok_or(ContractError::TokenChainMapNotFound)
 }
 
 pub fn read_destination_token_list(e: &Env, token_id: Address) -> Vec<DestinationChainDetails> {
     let chain_list = read_token_supported_chain_list(e, token_id.clone());
-    let mut default_list: Vec<DestinationChainDetails> = Vec::new(&e);
-    if chain_list.len() == 0 {
+    let mut default_list: Vec<DestinationChainDetails> = Vec::new(e);
+    if chain_list.is_empty() {
         default_list
     } else {
         for i in 0..chain_list.len() {
             let chain_id = chain_list.get_unchecked(i);
-            let chain_details: DestinationChainDetails =
-                read_destination_chain_token(e, token_id.clone(), chain_id).unwrap();
-
-            default_list.push_back(chain_details)
+            if let Ok(chain_details) = read_destination_chain_token(e, token_id.clone(), chain_id) {
+                default_list.push_back(chain_details)
+            }
         }
         default_list
     }
@@ -86,7 +96,7 @@ pub fn read_destination_token_list(e: &Env, token_id: Address) -> Vec<Destinatio
 
 pub fn read_token_supported_chain_list(e: &Env, token_id: Address) -> Vec<u32> {
     let key = DataKey::TokenSupportedChains(token_id);
-    let default_list: Vec<u32> = Vec::new(&e);
+    let default_list: Vec<u32> = Vec::new(e);
 
     e.storage()
         .instance()
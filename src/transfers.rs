@@ -0,0 +1,74 @@
+use soroban_sdk::{Address, Bytes, BytesN, Env, Map, Vec};
+
+use crate::data::{DataKey, BUMP_AMOUNT, LIFETIME_THRESHOLD};
+
+pub fn is_transfer_processed(e: &Env, tx_id: &Bytes) -> bool {
+    let key = DataKey::ProcessedTransfer(tx_id.clone());
+    e.storage().persistent().has(&key)
+}
+
+pub fn write_transfer_processed(e: &Env, tx_id: &Bytes) {
+    let key = DataKey::ProcessedTransfer(tx_id.clone());
+    e.storage().persistent().set(&key, &true);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+pub fn read_transfer_failure(e: &Env, tx_id: &Bytes) -> Option<u32> {
+    let key = DataKey::TransferFailure(tx_id.clone());
+    e.storage().persistent().get(&key)
+}
+
+pub fn write_transfer_failure(e: &Env, tx_id: &Bytes) {
+    let key = DataKey::TransferFailure(tx_id.clone());
+    e.storage().persistent().set(&key, &e.ledger().sequence());
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+pub fn clear_transfer_failure(e: &Env, tx_id: &Bytes) {
+    let key = DataKey::TransferFailure(tx_id.clone());
+    e.storage().persistent().remove(&key);
+}
+
+// Votes pushed by receptacles for a transfer that has not executed yet, as attestation hashes.
+pub fn read_pending_votes(e: &Env, tx_id: &Bytes) -> Map<Address, BytesN<32>> {
+    let key = DataKey::PendingVotes(tx_id.clone());
+    e.storage().persistent().get(&key).unwrap_or(Map::new(e))
+}
+
+pub fn write_pending_votes(e: &Env, tx_id: &Bytes, votes: &Map<Address, BytesN<32>>) {
+    let key = DataKey::PendingVotes(tx_id.clone());
+    e.storage().persistent().set(&key, votes);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+pub fn clear_pending_votes(e: &Env, tx_id: &Bytes) {
+    let key = DataKey::PendingVotes(tx_id.clone());
+    e.storage().persistent().remove(&key);
+}
+
+// Operators recorded as missing an executed transfer, kept with the winning attestation hash so a
+// late vote can still be credited.
+pub fn read_missed_votes(e: &Env, tx_id: &Bytes) -> Option<(BytesN<32>, Vec<Address>)> {
+    let key = DataKey::MissedVotes(tx_id.clone());
+    e.storage().persistent().get(&key)
+}
+
+pub fn write_missed_votes(e: &Env, tx_id: &Bytes, hash: &BytesN<32>, operators: &Vec<Address>) {
+    let key = DataKey::MissedVotes(tx_id.clone());
+    if operators.is_empty() {
+        e.storage().persistent().remove(&key);
+        return;
+    }
+    e.storage()
+        .persistent()
+        .set(&key, &(hash.clone(), operators.clone()));
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
@@ -0,0 +1,95 @@
+use soroban_sdk::{contracttype, BytesN, Env};
+
+use crate::{
+    data::{DataKey, DEFAULT_UPGRADE_DELAY, MIN_UPGRADE_DELAY},
+    error::ContractError,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PendingUpgrade {
+    pub wasm_hash: BytesN<32>,
+    pub executable_ledger: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PendingUpgradeDelay {
+    pub delay: u32,
+    pub effective_ledger: u32,
+}
+
+pub fn validate_upgrade_delay(delay: u32) -> Result<(), ContractError> {
+    if delay < MIN_UPGRADE_DELAY {
+        return Err(ContractError::InvalidUpgradeDelay);
+    }
+    Ok(())
+}
+
+// A pending decrease applies on its own once its ledger is reached.
+pub fn read_upgrade_delay(e: &Env) -> u32 {
+    if let Some(pending_delay) = read_pending_upgrade_delay(e) {
+        if e.ledger().sequence() >= pending_delay.effective_ledger {
+            return pending_delay.delay;
+        }
+    }
+
+    let key = DataKey::UpgradeDelay;
+    e.storage()
+        .instance()
+        .get(&key)
+        .unwrap_or(DEFAULT_UPGRADE_DELAY)
+}
+
+pub fn write_upgrade_delay(e: &Env, delay: u32) {
+    let key = DataKey::UpgradeDelay;
+    e.storage().instance().set(&key, &delay);
+    clear_pending_upgrade_delay(e);
+}
+
+pub fn read_pending_upgrade_delay(e: &Env) -> Option<PendingUpgradeDelay> {
+    let key = DataKey::PendingUpgradeDelay;
+    e.storage().instance().get(&key)
+}
+
+// Replaces any earlier decrease, which first settles if it already took effect, so the new one
+// waits out the delay in force now.
+pub fn write_pending_upgrade_delay(e: &Env, delay: u32) -> PendingUpgradeDelay {
+    let current_delay = read_upgrade_delay(e);
+    write_upgrade_delay(e, current_delay);
+
+    let key = DataKey::PendingUpgradeDelay;
+    let pending_delay = PendingUpgradeDelay {
+        delay,
+        effective_ledger: e.ledger().sequence() + current_delay,
+    };
+    e.storage().instance().set(&key, &pending_delay);
+
+    pending_delay
+}
+
+pub fn clear_pending_upgrade_delay(e: &Env) {
+    let key = DataKey::PendingUpgradeDelay;
+    e.storage().instance().remove(&key);
+}
+
+pub fn read_pending_upgrade(e: &Env) -> Option<PendingUpgrade> {
+    let key = DataKey::PendingUpgrade;
+    e.storage().instance().get(&key)
+}
+
+pub fn write_pending_upgrade(e: &Env, wasm_hash: BytesN<32>) -> PendingUpgrade {
+    let key = DataKey::PendingUpgrade;
+    let pending_upgrade = PendingUpgrade {
+        wasm_hash,
+        executable_ledger: e.ledger().sequence() + read_upgrade_delay(e),
+    };
+    e.storage().instance().set(&key, &pending_upgrade);
+
+    pending_upgrade
+}
+
+pub fn clear_pending_upgrade(e: &Env) {
+    let key = DataKey::PendingUpgrade;
+    e.storage().instance().remove(&key);
+}
@@ -0,0 +1,74 @@
+use soroban_sdk::{contracttype, Bytes, Env, Vec};
+
+use crate::{data::DataKey, error::ContractError};
+
+pub(crate) const DEFAULT_MAX_TRANSFERS: u32 = 5;
+pub(crate) const DEFAULT_MAX_RECEPTACLE_READS: u32 = 100;
+// Pending ids listed from each receptacle per upkeep, taken from the oldest.
+pub(crate) const MAX_LISTED_TRANSFERS: u32 = 20;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct UpkeepConfig {
+    pub max_transfers: u32, //transfers executed or cleared per upkeep; ids left queued only cost reads
+    pub max_receptacle_reads: u32, //receptacle reads per upkeep, listed ids and removals included
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct UpkeepSummary {
+    pub processed: u32, //transfers executed, or cleared from receptacles once already executed
+    pub skipped: u32,   //transfers examined without reaching consensus on an active route
+    pub failed: u32,    //transfers that reached consensus but could not be executed
+    pub remaining: u32, //listed transfers left for a later upkeep
+    pub reward: i128,   //keeper reward paid for this upkeep, for executed transfers only
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum UpkeepOutcome {
+    Executed,
+    NoConsensus,
+    RouteInactive, //consensus was reached but the token or chain is paused
+    AlreadyProcessed,
+    NotFound, //no node has a countable attestation for the id
+    Failed,   //consensus was reached but the bridge call failed or the attestation did not decode
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TransferVotes {
+    pub tx_id: Bytes,
+    pub votes: u32,                //countable attestations
+    pub leading_weight: i128,      //weight behind the most supported attestation
+    pub last_failure: Option<u32>, //ledger at which executing the transfer last failed
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct UpkeepCheck {
+    pub upkeep_needed: bool,
+    pub perform_data: Vec<Bytes>, //ids to pass to `perform_upkeep_for`, leaving out ones that failed
+    pub vote_threshold: i128,
+    pub transfers: Vec<TransferVotes>,
+}
+
+pub fn read_upkeep_config(e: &Env) -> UpkeepConfig {
+    let key = DataKey::UpkeepConfig;
+    e.storage().instance().get(&key).unwrap_or(UpkeepConfig {
+        max_transfers: DEFAULT_MAX_TRANSFERS,
+        max_receptacle_reads: DEFAULT_MAX_RECEPTACLE_READS,
+    })
+}
+
+pub fn write_upkeep_config(e: &Env, config: &UpkeepConfig) {
+    let key = DataKey::UpkeepConfig;
+    e.storage().instance().set(&key, config);
+}
+
+pub fn validate_upkeep_config(config: &UpkeepConfig) -> Result<(), ContractError> {
+    if config.max_transfers == 0 || config.max_receptacle_reads == 0 {
+        return Err(ContractError::InvalidUpkeepConfig);
+    }
+    Ok(())
+}